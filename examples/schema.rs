@@ -1,13 +1,39 @@
 use std::env::current_dir;
-use std::fs::create_dir_all;
+use std::fs::{create_dir_all, write};
 
-use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
+use cosmwasm_schema::{export_schema, export_schema_with_title, remove_schemas, schema_for};
 
 use dsrv_poap::msg::{
-    ExecuteMsg, GetEventResponse, InstantiateMsg, ListAllEventsResponse, ListAttendeesResponse,
-    ListMyBadgesResponse, QueryMsg,
+    AchievementResponse, AirdropProofResponse, AllowlistedEventsResponse, ApprovalResponse,
+    ApprovalsResponse, ArchivedEventResponse, AttendanceProofResponse, AttendanceRootResponse,
+    AttendedOccurrencesResponse, AttendedResponse, AuditLogResponse, BadgeClassesResponse,
+    BadgeCountResponse, BadgeKeyResponse, BadgeProvenanceResponse, ChangesSinceResponse,
+    CheckGateResponse, CheckInResponse, CheckMembersResponse, ClaimAllReservedResponse,
+    ClaimCodeResponse, ClaimSecretsResponse, CompletedSeriesResponse, ConfigResponse,
+    ContractInfoResponse, Cw20TicketTokenResponse, DepositResponse, EventAttendanceResponse,
+    EventCouncilResponse, EventFlagsResponse, EventHistoryResponse, EventRatingResponse,
+    EventRecoveryResponse, EventRolesResponse, EventStatusResponse, EventTemplatesResponse,
+    ExecuteMsg, FeesResponse, FlaggedEventsResponse, ForeignAddressBadgesResponse,
+    GasBudgetResponse, GetBadgesResponse, GetEventResponse, GetSeriesResponse, HasBadgeResponse,
+    InstantiateMsg, ListAllEventsResponse, ListArchivedEventsResponse, ListAttendeesResponse,
+    ListEventProposalsResponse, ListEventSummariesResponse, ListForeignBadgesResponse,
+    ListKiosksResponse, ListMintersResponse, ListMyBadgesResponse, ListOccurrencesResponse,
+    ListOrganizersResponse, ListReservationsResponse, ListTicketsResponse, LocalizedEventResponse,
+    MigrateMsg, MigrationStatusResponse, MintBadgesResponse, MintForEventsResponse,
+    MintHistogramResponse, MintHooksResponse, MinterResponse, NextEventResponse, NftInfoResponse,
+    NftTicketCollectionResponse, NumTokensResponse, OpenClaimResponse, OperatorResponse,
+    OperatorsResponse, OrganizerStatsResponse, PermissionsResponse, PoapHookMsg, QueryMsg,
+    RaffleResponse, RawStateResponse, RecentBadgesResponse, RedemptionResponse, ReferralsResponse,
+    RemoteBadgeResponse, RevokeBadgesResponse, RewardPoolResponse, SampleAttendeesResponse,
+    SeriesProgressResponse, SeriesStreakResponse, SubscriptionPlansResponse, SubscriptionResponse,
+    SudoMsg, TokensResponse, UniqueHoldersResponse, VerifyArchivedAttendeeResponse,
+    WaitlistPositionResponse,
 };
+use dsrv_poap::ownership::Ownership;
 
+// cosmwasm_schema::write_api! needs cosmwasm-schema 1.1, so every message and response is
+// exported explicitly. Keep this list in sync with QueryMsg when adding queries.
+// query_responses.json maps each query to its response, like write_api's "responses".
 fn main() {
     let mut out_dir = current_dir().unwrap();
     out_dir.push("schema");
@@ -17,8 +43,105 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(SudoMsg), &out_dir);
+    export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(ContractInfoResponse), &out_dir);
+    export_schema_with_title(
+        &schema_for!(Ownership<String>),
+        &out_dir,
+        "OwnershipResponse",
+    );
     export_schema(&schema_for!(GetEventResponse), &out_dir);
+    export_schema(&schema_for!(LocalizedEventResponse), &out_dir);
     export_schema(&schema_for!(ListMyBadgesResponse), &out_dir);
+    export_schema(&schema_for!(HasBadgeResponse), &out_dir);
+    export_schema(&schema_for!(ClaimCodeResponse), &out_dir);
+    export_schema(&schema_for!(BadgeProvenanceResponse), &out_dir);
+    export_schema(&schema_for!(UniqueHoldersResponse), &out_dir);
+    export_schema(&schema_for!(BadgeCountResponse), &out_dir);
+    export_schema(&schema_for!(CheckInResponse), &out_dir);
+    export_schema(&schema_for!(RewardPoolResponse), &out_dir);
+    export_schema(&schema_for!(DepositResponse), &out_dir);
+    export_schema(&schema_for!(EventRatingResponse), &out_dir);
+    export_schema(&schema_for!(ListReservationsResponse), &out_dir);
+    export_schema(&schema_for!(ListMintersResponse), &out_dir);
+    export_schema(&schema_for!(ReferralsResponse), &out_dir);
+    export_schema(&schema_for!(ChangesSinceResponse), &out_dir);
+    export_schema(&schema_for!(NftInfoResponse), &out_dir);
+    export_schema(&schema_for!(EventFlagsResponse), &out_dir);
+    export_schema(&schema_for!(FlaggedEventsResponse), &out_dir);
+    export_schema(&schema_for!(ListOccurrencesResponse), &out_dir);
+    export_schema(&schema_for!(AttendedOccurrencesResponse), &out_dir);
     export_schema(&schema_for!(ListAllEventsResponse), &out_dir);
     export_schema(&schema_for!(ListAttendeesResponse), &out_dir);
+    export_schema(&schema_for!(GetSeriesResponse), &out_dir);
+    export_schema(&schema_for!(SeriesProgressResponse), &out_dir);
+    export_schema(&schema_for!(CompletedSeriesResponse), &out_dir);
+    export_schema(&schema_for!(AttendedResponse), &out_dir);
+    export_schema(&schema_for!(GetBadgesResponse), &out_dir);
+    export_schema(&schema_for!(NextEventResponse), &out_dir);
+    export_schema(&schema_for!(WaitlistPositionResponse), &out_dir);
+    export_schema(&schema_for!(ListTicketsResponse), &out_dir);
+    export_schema(&schema_for!(CheckGateResponse), &out_dir);
+    export_schema(&schema_for!(SubscriptionPlansResponse), &out_dir);
+    export_schema(&schema_for!(SubscriptionResponse), &out_dir);
+    export_schema(&schema_for!(EventCouncilResponse), &out_dir);
+    export_schema(&schema_for!(ListEventProposalsResponse), &out_dir);
+    export_schema(&schema_for!(ApprovalResponse), &out_dir);
+    export_schema(&schema_for!(ApprovalsResponse), &out_dir);
+    export_schema(&schema_for!(OperatorResponse), &out_dir);
+    export_schema(&schema_for!(OperatorsResponse), &out_dir);
+    export_schema(&schema_for!(MinterResponse), &out_dir);
+    export_schema(&schema_for!(TokensResponse), &out_dir);
+    export_schema(&schema_for!(NumTokensResponse), &out_dir);
+    export_schema(&schema_for!(EventTemplatesResponse), &out_dir);
+    export_schema(&schema_for!(ListOrganizersResponse), &out_dir);
+    export_schema(&schema_for!(OpenClaimResponse), &out_dir);
+    export_schema(&schema_for!(RecentBadgesResponse), &out_dir);
+    export_schema(&schema_for!(ArchivedEventResponse), &out_dir);
+    export_schema(&schema_for!(ListArchivedEventsResponse), &out_dir);
+    export_schema(&schema_for!(VerifyArchivedAttendeeResponse), &out_dir);
+    export_schema(&schema_for!(ListEventSummariesResponse), &out_dir);
+    export_schema(&schema_for!(MintForEventsResponse), &out_dir);
+    export_schema(&schema_for!(RevokeBadgesResponse), &out_dir);
+    export_schema(&schema_for!(ClaimAllReservedResponse), &out_dir);
+    export_schema(&schema_for!(OrganizerStatsResponse), &out_dir);
+    export_schema(&schema_for!(EventRolesResponse), &out_dir);
+    export_schema(&schema_for!(PermissionsResponse), &out_dir);
+    export_schema(&schema_for!(AuditLogResponse), &out_dir);
+    export_schema(&schema_for!(EventHistoryResponse), &out_dir);
+    export_schema(&schema_for!(AttendanceRootResponse), &out_dir);
+    export_schema(&schema_for!(AttendanceProofResponse), &out_dir);
+    export_schema(&schema_for!(RemoteBadgeResponse), &out_dir);
+    export_schema(&schema_for!(EventRecoveryResponse), &out_dir);
+    export_schema(&schema_for!(AllowlistedEventsResponse), &out_dir);
+    export_schema(&schema_for!(ListKiosksResponse), &out_dir);
+    export_schema(&schema_for!(ListForeignBadgesResponse), &out_dir);
+    export_schema(&schema_for!(ForeignAddressBadgesResponse), &out_dir);
+    export_schema(&schema_for!(RawStateResponse), &out_dir);
+    export_schema(&schema_for!(MigrationStatusResponse), &out_dir);
+    export_schema(&schema_for!(NftTicketCollectionResponse), &out_dir);
+    export_schema(&schema_for!(Cw20TicketTokenResponse), &out_dir);
+    export_schema(&schema_for!(RaffleResponse), &out_dir);
+    export_schema(&schema_for!(SampleAttendeesResponse), &out_dir);
+    export_schema(&schema_for!(EventStatusResponse), &out_dir);
+    export_schema(&schema_for!(GasBudgetResponse), &out_dir);
+    export_schema(&schema_for!(RedemptionResponse), &out_dir);
+    export_schema(&schema_for!(ClaimSecretsResponse), &out_dir);
+    export_schema(&schema_for!(BadgeKeyResponse), &out_dir);
+    export_schema(&schema_for!(FeesResponse), &out_dir);
+    export_schema(&schema_for!(SeriesStreakResponse), &out_dir);
+    export_schema(&schema_for!(AchievementResponse), &out_dir);
+    export_schema(&schema_for!(CheckMembersResponse), &out_dir);
+    export_schema(&schema_for!(AirdropProofResponse), &out_dir);
+    export_schema(&schema_for!(BadgeClassesResponse), &out_dir);
+    export_schema(&schema_for!(EventAttendanceResponse), &out_dir);
+    export_schema(&schema_for!(MintHistogramResponse), &out_dir);
+    export_schema(&schema_for!(MintHooksResponse), &out_dir);
+    export_schema(&schema_for!(PoapHookMsg), &out_dir);
+    export_schema(&schema_for!(MintBadgesResponse), &out_dir);
+
+    let responses = serde_json::to_string_pretty(&QueryMsg::response_schemas()).unwrap();
+    write(out_dir.join("query_responses.json"), responses + "\n").unwrap();
 }
@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::iter::Peekable;
+use std::ops::Bound;
+
+use cosmwasm_std::{Order, Record, Storage};
+
+/// Buffers the writes made through it over a read-only storage, so a step that fails halfway
+/// can be dropped without leaving its partial writes behind. `into_changes` hands the writes
+/// over to be applied once the step succeeded
+pub struct StorageCache<'a> {
+    storage: &'a dyn Storage,
+    // None for removed keys
+    changes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+/// A buffered write, None for a removed key
+type Change<'b> = (&'b Vec<u8>, &'b Option<Vec<u8>>);
+
+/// The writes buffered by a StorageCache
+pub struct Changes(BTreeMap<Vec<u8>, Option<Vec<u8>>>);
+
+impl<'a> StorageCache<'a> {
+    pub fn new(storage: &'a dyn Storage) -> Self {
+        StorageCache {
+            storage,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    pub fn into_changes(self) -> Changes {
+        Changes(self.changes)
+    }
+}
+
+impl Changes {
+    pub fn apply(self, storage: &mut dyn Storage) {
+        for (key, value) in self.0 {
+            match value {
+                Some(value) => storage.set(&key, &value),
+                None => storage.remove(&key),
+            }
+        }
+    }
+}
+
+impl<'a> Storage for StorageCache<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.changes.get(key) {
+            Some(value) => value.clone(),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        // BTreeMap::range panics on inverted bounds, which the storages treat as an empty range
+        let changes: Box<dyn Iterator<Item = Change>> = match (start, end) {
+            (Some(start), Some(end)) if start > end => Box::new(std::iter::empty()),
+            _ => {
+                let bounds = (
+                    start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+                    end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+                );
+                let changes = self.changes.range(bounds);
+                match order {
+                    Order::Ascending => Box::new(changes),
+                    Order::Descending => Box::new(changes.rev()),
+                }
+            }
+        };
+        Box::new(Merge {
+            base: self.storage.range(start, end, order).peekable(),
+            changes: changes.peekable(),
+            order,
+        })
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.changes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.changes.insert(key.to_vec(), None);
+    }
+}
+
+/// The records of the underlying storage with the buffered writes laid over them, both being
+/// in the same order
+struct Merge<'b, B: Iterator<Item = Record>, C: Iterator<Item = Change<'b>>> {
+    base: Peekable<B>,
+    changes: Peekable<C>,
+    order: Order,
+}
+
+impl<'b, B, C> Iterator for Merge<'b, B, C>
+where
+    B: Iterator<Item = Record>,
+    C: Iterator<Item = Change<'b>>,
+{
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        loop {
+            let next = match (self.base.peek(), self.changes.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((base, _)), Some((change, _))) => match self.order {
+                    Order::Ascending => base.cmp(change),
+                    Order::Descending => (*change).cmp(base),
+                },
+            };
+            match next {
+                Ordering::Less => return self.base.next(),
+                // the buffered write replaces the record
+                Ordering::Equal => {
+                    self.base.next();
+                }
+                Ordering::Greater => {}
+            }
+            if let Some((key, Some(value))) = self.changes.next() {
+                return Some((key.clone(), value.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    #[test]
+    fn writes_laid_over_the_storage() {
+        let mut storage = MockStorage::new();
+        for key in [b"a", b"b", b"c", b"d"] {
+            storage.set(key, key);
+        }
+        let mut cache = StorageCache::new(&storage);
+        cache.set(b"b", b"B");
+        cache.remove(b"c");
+        cache.set(b"e", b"e");
+        assert_eq!(cache.get(b"b"), Some(b"B".to_vec()));
+        assert_eq!(cache.get(b"c"), None);
+        let keys = |order| cache.range(Some(b"b"), None, order).collect::<Vec<_>>();
+        let record = |key: &[u8], value: &[u8]| (key.to_vec(), value.to_vec());
+        assert_eq!(
+            keys(Order::Ascending),
+            vec![record(b"b", b"B"), record(b"d", b"d"), record(b"e", b"e")]
+        );
+        assert_eq!(
+            keys(Order::Descending),
+            vec![record(b"e", b"e"), record(b"d", b"d"), record(b"b", b"B")]
+        );
+        assert_eq!(
+            cache
+                .range(Some(b"d"), Some(b"b"), Order::Ascending)
+                .count(),
+            0
+        );
+
+        // nothing reaches the storage until the changes are applied
+        assert_eq!(storage.get(b"c"), Some(b"c".to_vec()));
+        let changes = cache.into_changes();
+        changes.apply(&mut storage);
+        assert_eq!(storage.get(b"c"), None);
+        assert_eq!(storage.get(b"e"), Some(b"e".to_vec()));
+    }
+}
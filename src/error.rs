@@ -1,37 +1,790 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
+use crate::ownership::OwnershipError;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("This message does not accept funds")]
+    UnexpectedFunds,
+
+    #[error("Cannot migrate from a different contract: {0}")]
+    CannotMigrate(String),
+
     #[error("Event name was already registered")]
     EventAlreadyRegistered,
 
-    #[error("Event name less than 2 characters")]
-    NameTooShort,
+    #[error("No event named {name}")]
+    EventNotFound { name: String },
+
+    #[error("Name less than {0} characters")]
+    NameTooShort(u32),
+
+    #[error("Name more than {0} characters")]
+    NameTooLong(u32),
+
+    #[error("Description more than {0} characters")]
+    DescriptionTooLong(u32),
+
+    #[error("Image URL more than {0} characters")]
+    ImageURLTooLong(u32),
+
+    #[error("Metadata more than {0} characters")]
+    MetadataTooLong(u32),
+
+    #[error("Metadata must be valid JSON")]
+    InvalidMetadata,
+
+    #[error("Invalid language tag: {0}")]
+    InvalidLanguage(String),
 
-    #[error("Event name more than 100 characters")]
-    NameTooLong,
+    #[error("An event can be translated to at most {0} languages")]
+    TooManyTranslations(usize),
 
-    #[error("Image URL must be https://, was {0}")]
+    #[error("Invalid validation limits")]
+    InvalidLimits,
+
+    #[error("Image URL must be a valid https:// URL, was {0}")]
     InvalidImageURL(String),
 
+    #[error("External URL must be a valid https:// URL, was {0}")]
+    InvalidExternalURL(String),
+
+    #[error("External URL more than {0} characters")]
+    ExternalURLTooLong(u32),
+
+    #[error("Animation URL must be a valid https:// URL, was {0}")]
+    InvalidAnimationURL(String),
+
+    #[error("Image URL must be hosted on an allowed domain, was {0}")]
+    ImageDomainNotAllowed(String),
+
+    #[error("Invalid image domain: {0}")]
+    InvalidImageDomain(String),
+
+    #[error("At most {0} image domains can be allowed")]
+    TooManyImageDomains(usize),
+
+    #[error("Checksum must be a hex-encoded sha256, was {0}")]
+    InvalidChecksum(String),
+
+    #[error("Timezone must be a UTC offset like +09:00 or an IANA name, was {0}")]
+    InvalidTimezone(String),
+
+    #[error("Background color must be six hex digits, was {0}")]
+    InvalidBackgroundColor(String),
+
     // #[error("Image URL must be https://, was {url}")]
     // InvalidImageURL{url: String},
+    #[error("Badges cannot expire before the event ends")]
+    InvalidBadgeExpiry,
+
     #[error("Event start time before end time")]
     StartBeforeEnd,
 
     #[error("The event is already over")]
     EventAlreadyOver,
 
+    #[error("Claim deadline must not be before the event ends")]
+    InvalidClaimDeadline,
+
+    #[error("The claim window for this event has closed")]
+    ClaimWindowClosed,
+
+    #[error("Badges can still be claimed for this event")]
+    ClaimWindowOpen,
+
+    #[error("Reward amount must not be zero")]
+    InvalidReward,
+
+    #[error("The event has no reward pool")]
+    NoRewardPool,
+
+    #[error("Registering an event requires a deposit of {0}")]
+    DepositRequired(String),
+
+    #[error("No deposit is held for this event")]
+    NoDeposit,
+
+    #[error("The event is not over yet")]
+    EventNotOver,
+
     #[error("The event hasn't started yet")]
     EventNotStarted,
 
-    #[error("The attendee already has a badge for this event")]
-    BadgeAlreadyIssued,
+    #[error("{attendee} already has a badge for {event}")]
+    BadgeAlreadyIssued { event: String, attendee: String },
+
+    #[error("Score must be between 1 and 5")]
+    InvalidScore,
+
+    #[error("Comment more than {0} characters")]
+    CommentTooLong(usize),
+
+    #[error("The sender has no badge for this event")]
+    NoBadge,
+
+    #[error("Badges of this event cannot be transferred")]
+    NotTransferable,
+
+    #[error("This event was already rated by the sender")]
+    AlreadyRated,
+
+    #[error("A badge was already reserved for this attendee")]
+    AlreadyReserved,
+
+    #[error("No reserved badge for this event")]
+    ReservationNotFound,
+
+    #[error("Claim code hashes must be hex encoded sha256, was {0}")]
+    InvalidCodeHash(String),
+
+    #[error("No more than {0} claim codes may be added at once")]
+    TooManyCodes(u32),
+
+    #[error("Claim code was already added: {0}")]
+    DuplicateCode(String),
+
+    #[error("Invalid claim code")]
+    InvalidCode,
+
+    #[error("This claim code was already used")]
+    CodeAlreadyUsed,
+
+    #[error("Nonces must be valid for 1 to {0} seconds")]
+    InvalidNonceWindow(u64),
+
+    #[error("Nonce does not match the one shown by the kiosk")]
+    InvalidNonce,
+
+    #[error("The kiosk nonce has expired")]
+    NonceExpired,
+
+    #[error("Badges of this event are only minted on check-out")]
+    CheckInRequired,
+
+    #[error("The event does not use check-in")]
+    CheckInNotRequired,
+
+    #[error("The attendee is already checked in")]
+    AlreadyCheckedIn,
+
+    #[error("The attendee is not checked in")]
+    NotCheckedIn,
+
+    #[error("Occurrences must be within the event's start and end time")]
+    OccurrenceOutsideEvent,
+
+    #[error("Address is already an owner of this event")]
+    AlreadyOwner,
+
+    #[error("Address is not a co-owner of this event")]
+    NotCoOwner,
+
+    #[error("An event can have at most {0} co-owners")]
+    TooManyCoOwners(usize),
+
+    #[error("Address is not a minter of this event")]
+    NotMinter,
+
+    #[error("The sender's role grant for this event has expired")]
+    MinterExpired,
+
+    #[error("Role grant expiry is already in the past")]
+    InvalidMinterExpiry,
+
+    #[error("Series name was already registered")]
+    SeriesAlreadyRegistered,
+
+    #[error("A series can't have more than {0} events")]
+    SeriesFull(u32),
+
+    #[error("Event is already part of this series")]
+    AlreadyInSeries,
+
+    #[error("The event is frozen")]
+    EventFrozen,
+
+    #[error("An event can have at most {0} tiers")]
+    TooManyTiers(usize),
+
+    #[error("Invalid or duplicate tier name: {0}")]
+    InvalidTier(String),
+
+    #[error("The event has no tier named {0}")]
+    UnknownTier(String),
+
+    #[error("Metadata can't be edited once badges were minted")]
+    MetadataLocked,
+
+    #[error("The flag reason must be between 1 and {0} characters")]
+    InvalidFlagReason(usize),
+
+    #[error("The sender already flagged this event")]
+    AlreadyFlagged,
+
+    #[error("The sender is not allowed to register events")]
+    RegistrationRestricted,
+
+    #[error("The address is not an approved organizer")]
+    NotOrganizer,
+
+    #[error("The event is waiting for the admin's approval")]
+    EventPending,
+
+    #[error("The event is not waiting for approval")]
+    EventNotPending,
+
+    #[error("Badge note more than {0} characters")]
+    NoteTooLong(usize),
+
+    #[error("No more than {0} badges may be minted for this event per block")]
+    RateLimitExceeded(u32),
+
+    #[error("Attendees can't refer themselves")]
+    SelfReferral,
+
+    #[error("The event is full, with {capacity} badges")]
+    EventFull { capacity: u32 },
+
+    #[error("The event is not full, claim a badge instead")]
+    EventNotFull,
+
+    #[error("The sender is already on the waitlist")]
+    AlreadyWaitlisted,
+
+    #[error("The sender is not on the waitlist")]
+    NotWaitlisted,
+
+    #[error("Waitlist position {position} but only {free_slots} free slots")]
+    NotYourTurn { position: u32, free_slots: u32 },
+
+    #[error("{holder} has no ticket for {event}")]
+    TicketNotFound { event: String, holder: String },
+
+    #[error("{holder} already has a ticket for {event}")]
+    AlreadyHasTicket { event: String, holder: String },
+
+    #[error("An active subscription is required to register events")]
+    SubscriptionRequired,
+
+    #[error("Subscription plan {plan} not found")]
+    SubscriptionPlanNotFound { plan: String },
+
+    #[error("Subscribing requires a payment of exactly {0}")]
+    SubscriptionFee(String),
+
+    #[error("Invalid subscription plan: the price and max events must not be zero")]
+    InvalidSubscriptionPlan,
+
+    #[error("The plan allows {max_events} events per period")]
+    EventQuotaReached { max_events: u32 },
+
+    #[error("The plan allows a capacity of at most {max_capacity}")]
+    CapacityAboveLimit { max_capacity: u32 },
+
+    #[error("The event has a council, this action must be proposed and approved")]
+    CouncilApprovalRequired,
+
+    #[error("The event has no council")]
+    NoEventCouncil,
+
+    #[error(
+        "Invalid council: members need a non-zero weight, and the threshold must be reachable"
+    )]
+    InvalidCouncil,
+
+    #[error("The sender is not a member of the event council")]
+    NotCouncilMember,
+
+    #[error("Proposal {id} not found")]
+    ProposalNotFound { id: u64 },
+
+    #[error("The sender already approved this proposal")]
+    AlreadyApproved,
+
+    #[error("Approval expiry is already in the past")]
+    InvalidApprovalExpiry,
+
+    #[error("Invalid collection info: the name and an alphanumeric symbol are required")]
+    InvalidCollectionInfo,
+
+    #[error("Event template {template} not found")]
+    TemplateNotFound { template: String },
+
+    #[error("The badge was exported to {target}")]
+    BadgeExported { target: String },
+
+    #[error("Invalid open claim window: it must last at most {0} seconds, within the mint window")]
+    InvalidOpenClaimWindow(u64),
+
+    #[error("The event is not open for claims")]
+    OpenClaimClosed,
+
+    #[error("Mint memo more than {0} characters")]
+    MemoTooLong(usize),
+
+    #[error("The event was archived")]
+    EventArchived,
+
+    #[error("At most {0} events at once")]
+    TooManyEvents(usize),
+
+    #[error("Address has no role in this event")]
+    NoRole,
+
+    #[error("Challenge period must be at most {0} seconds")]
+    InvalidChallengePeriod(u64),
+
+    #[error("Badge is provisional until {until}")]
+    BadgeProvisional { until: u64 },
+
+    #[error("Badge is not provisional")]
+    NotProvisional,
+
+    #[error("The claim of this address was challenged")]
+    ClaimChallenged,
+
+    #[error("No trusted root for event {event} on {chain}")]
+    RemoteRootNotFound { chain: String, event: String },
+
+    #[error("Proof does not match the remote attendance root")]
+    InvalidRemoteProof,
+
+    #[error("At most {0} attendees at once")]
+    TooManyAttendees(usize),
+
+    #[error("No recovery was announced for this event")]
+    RecoveryNotFound,
+
+    #[error("Recovery can't be completed before {until}")]
+    RecoveryTimelocked { until: u64 },
+
+    #[error("Kiosk may not mint at this time")]
+    KioskClosed,
+
+    #[error("Kiosk may only mint in tier {tier}")]
+    KioskTierMismatch { tier: String },
+
+    #[error("No kiosk registered at this address")]
+    KioskNotFound,
+
+    #[error("Minter used up their badge quota")]
+    MintQuotaExhausted,
+
+    #[error("Cannot resolve name {0}")]
+    UnresolvedName(String),
+
+    #[error("Invalid foreign address {0}")]
+    InvalidForeignAddress(String),
+
+    #[error("Attendees of private events are only recorded by hash")]
+    PrivateEvent,
+
+    #[error("The event is not private")]
+    EventNotPrivate,
+
+    #[error("A migration is in progress, call ContinueMigration until it completes")]
+    MigrationInProgress,
+
+    #[error("No migration in progress")]
+    NoMigrationInProgress,
+
+    #[error("The event does not accept tickets of this collection")]
+    UnknownTicketCollection,
+
+    #[error("The event does not accept this token as a ticket")]
+    UnknownTicketToken,
+
+    #[error("A ticket costs exactly {expected} tokens")]
+    WrongTicketAmount { expected: Uint128 },
+
+    #[error("No Nois proxy is configured")]
+    NoRandomnessProxy,
+
+    #[error("The event already has a raffle")]
+    RaffleExists,
+
+    #[error("No raffle of this event is waiting for randomness")]
+    RaffleNotDrawing,
+
+    #[error("The event has {0} sponsors already")]
+    TooManySponsors(u32),
+
+    #[error("Attach the funds to sponsor the event with")]
+    NoSponsorshipFunds,
+
+    #[error("The event was not approved or issued no badge")]
+    EventNotSuccessful,
+
+    #[error("The event succeeded, its sponsorships go to the owner")]
+    SponsorshipLocked,
+
+    #[error("The event was not cancelled")]
+    EventNotCancelled,
+
+    #[error("The publication time must be before the end of the event")]
+    InvalidPublishTime,
+
+    #[error("Invalid badge attribute: {0}")]
+    InvalidBadgeAttribute(String),
+
+    #[error("The event has no gas budget")]
+    NoGasBudget,
+
+    #[error("The gas budget can't cover the allowances")]
+    GasBudgetExhausted,
+
+    #[error("The badge has expired")]
+    BadgeExpired,
+
+    #[error("The perk was already redeemed for this badge")]
+    AlreadyRedeemed,
+
+    #[error("The claim secret is not valid at this time")]
+    ClaimSecretInactive,
+
+    #[error("Claim secret windows must end in the future and last at most {0} seconds")]
+    InvalidClaimSecretWindow(u64),
+
+    #[error("No more than {0} claim secrets may be scheduled")]
+    TooManyClaimSecrets(u32),
+
+    #[error("Invalid public key or signature")]
+    InvalidKeySignature,
+
+    #[error("Organizers may have {0} events that haven't ended at once")]
+    TooManyActiveEvents(u32),
+
+    #[error("Minting is paused for this event")]
+    MintingPaused,
+
+    #[error("The holder erased the badge's data")]
+    BadgeDataErased,
+
+    #[error("A fee of {0} is required")]
+    FeeRequired(String),
+
+    #[error("Fee discounts must be between 0 and 1, at most {0} of them")]
+    InvalidFeeDiscounts(usize),
+
+    #[error("A streak must span 2 to {0} events, and its badge can't be part of the series")]
+    InvalidStreak(u32),
+
+    #[error("An achievement requires 1 to {0} other events, each once")]
+    InvalidAchievement(u32),
+
+    #[error("The achievement also requires a badge of {0}")]
+    AchievementIncomplete(String),
+
+    #[error("A badge can have at most {0} approved spenders")]
+    TooManyApprovals(usize),
+
+    #[error("An event can grant at most {0} roles")]
+    TooManyRoles(usize),
+
+    #[error("An event can have at most {0} kiosks")]
+    TooManyKiosks(usize),
+
+    #[error("An event can have at most {0} occurrences")]
+    TooManyOccurrences(u32),
+
+    #[error("The waitlist is full at {0} addresses")]
+    WaitlistFull(u64),
+
+    #[error("A fee can be priced in at most {0} denoms")]
+    TooManyFeeDenoms(usize),
+
+    #[error("An event can be part of at most {0} series with a streak badge")]
+    TooManyStreaks(usize),
+
+    #[error("The event must be frozen first")]
+    EventNotFrozen,
+
+    #[error("The event has no attendees")]
+    NoAttendees,
+
+    #[error("An airdrop was already created for the event with this contract")]
+    AirdropExists,
+
+    #[error("The airdrop must be sent {0}")]
+    AirdropFunds(String),
+
+    #[error("Coordinates must be within 90 degrees of latitude and 180 of longitude")]
+    InvalidLocation,
+
+    #[error("Venue name can be at most {0} bytes")]
+    VenueTooLong(u32),
+
+    #[error("Names can't contain \"::\", it separates an event from its badge classes")]
+    ReservedEventName,
+
+    #[error("An event can have at most {0} badge classes")]
+    TooManyBadgeClasses(usize),
+
+    #[error("Badge classes can't have classes of their own")]
+    NestedBadgeClass,
+
+    #[error("The badge metadata is frozen")]
+    BadgeMetadataFrozen,
+
+    #[error("Deployment name must be 1 to 64 characters, and the description within the limits")]
+    InvalidDeploymentInfo,
+
+    #[error("An event can have at most {0} mint hooks")]
+    TooManyHooks(usize),
+
+    #[error("Unknown reply id {0}")]
+    UnknownReplyId(u64),
+
+    #[error("A partial batch of badges for {0} is pending, continue it first")]
+    BatchPending(String),
+
+    #[error("No partial batch of badges for {0}")]
+    NoPendingBatch(String),
+
+    #[error("Only badge holders can set a nickname")]
+    NotAHolder,
+
+    #[error(
+        "Nicknames have 1 to {0} characters, without control characters or surrounding spaces"
+    )]
+    InvalidNickname(u32),
+
+    #[error("Event extension more than {0} bytes")]
+    ExtensionTooLarge(usize),
+
+    #[error("Migrating a contract without a config requires an admin")]
+    MigrationAdminRequired,
+
+    #[error("The mint rate limit must allow at least one mint per block")]
+    InvalidRateLimit,
+
+    #[error("The fee paid does not split evenly between the {0} attendees")]
+    UnevenBatchFee(usize),
+
+    #[error("Perk ids have 1 to {max} characters")]
+    InvalidPerkId { max: usize },
+
+    #[error("The ticket amount must not be zero")]
+    InvalidTicketAmount,
+
+    #[error("A raffle needs at least one winner")]
+    NoRaffleWinners,
+
+    #[error("The randomness must be 32 hex encoded bytes")]
+    InvalidRandomness,
+
+    #[error("The gas allowance must not be zero")]
+    InvalidGasAllowance,
+}
+
+impl ContractError {
+    /// Stable numeric code, so frontends can map errors to their own messages without
+    /// parsing the text. Codes are never reused: new variants get the next free number
+    pub fn code(&self) -> u32 {
+        match self {
+            ContractError::Std(_) => 1,
+            ContractError::Ownership(_) => 2,
+            ContractError::Unauthorized { .. } => 3,
+            ContractError::UnexpectedFunds => 4,
+            ContractError::CannotMigrate(_) => 5,
+            ContractError::EventAlreadyRegistered => 6,
+            ContractError::EventNotFound { .. } => 7,
+            ContractError::NameTooShort(_) => 8,
+            ContractError::NameTooLong(_) => 9,
+            ContractError::DescriptionTooLong(_) => 10,
+            ContractError::ImageURLTooLong(_) => 11,
+            ContractError::MetadataTooLong(_) => 12,
+            ContractError::InvalidMetadata => 13,
+            ContractError::InvalidLanguage(_) => 14,
+            ContractError::TooManyTranslations(_) => 15,
+            ContractError::InvalidLimits => 16,
+            ContractError::InvalidImageURL(_) => 17,
+            ContractError::InvalidExternalURL(_) => 18,
+            ContractError::ExternalURLTooLong(_) => 19,
+            ContractError::InvalidAnimationURL(_) => 20,
+            ContractError::ImageDomainNotAllowed(_) => 21,
+            ContractError::InvalidImageDomain(_) => 22,
+            ContractError::TooManyImageDomains(_) => 23,
+            ContractError::InvalidChecksum(_) => 24,
+            ContractError::InvalidBackgroundColor(_) => 25,
+            ContractError::InvalidBadgeExpiry => 26,
+            ContractError::StartBeforeEnd => 27,
+            ContractError::EventAlreadyOver => 28,
+            ContractError::InvalidClaimDeadline => 29,
+            ContractError::ClaimWindowClosed => 30,
+            ContractError::ClaimWindowOpen => 31,
+            ContractError::InvalidReward => 32,
+            ContractError::NoRewardPool => 33,
+            ContractError::DepositRequired(_) => 34,
+            ContractError::NoDeposit => 35,
+            ContractError::EventNotOver => 36,
+            ContractError::EventNotStarted => 37,
+            ContractError::BadgeAlreadyIssued { .. } => 38,
+            ContractError::InvalidScore => 39,
+            ContractError::CommentTooLong(_) => 40,
+            ContractError::NoBadge => 41,
+            ContractError::NotTransferable => 42,
+            ContractError::AlreadyRated => 43,
+            ContractError::AlreadyReserved => 44,
+            ContractError::ReservationNotFound => 45,
+            ContractError::InvalidCodeHash(_) => 46,
+            ContractError::TooManyCodes(_) => 47,
+            ContractError::DuplicateCode(_) => 48,
+            ContractError::InvalidCode => 49,
+            ContractError::CodeAlreadyUsed => 50,
+            ContractError::InvalidNonceWindow(_) => 51,
+            ContractError::InvalidNonce => 52,
+            ContractError::NonceExpired => 53,
+            ContractError::CheckInRequired => 54,
+            ContractError::CheckInNotRequired => 55,
+            ContractError::AlreadyCheckedIn => 56,
+            ContractError::NotCheckedIn => 57,
+            ContractError::OccurrenceOutsideEvent => 58,
+            ContractError::AlreadyOwner => 59,
+            ContractError::NotCoOwner => 60,
+            ContractError::TooManyCoOwners(_) => 61,
+            ContractError::NotMinter => 62,
+            ContractError::MinterExpired => 63,
+            ContractError::InvalidMinterExpiry => 64,
+            ContractError::SeriesAlreadyRegistered => 65,
+            ContractError::SeriesFull(_) => 66,
+            ContractError::AlreadyInSeries => 67,
+            ContractError::EventFrozen => 68,
+            ContractError::TooManyTiers(_) => 69,
+            ContractError::InvalidTier(_) => 70,
+            ContractError::UnknownTier(_) => 71,
+            ContractError::MetadataLocked => 72,
+            ContractError::InvalidFlagReason(_) => 73,
+            ContractError::AlreadyFlagged => 74,
+            ContractError::RegistrationRestricted => 75,
+            ContractError::NotOrganizer => 76,
+            ContractError::EventPending => 77,
+            ContractError::EventNotPending => 78,
+            ContractError::NoteTooLong(_) => 79,
+            ContractError::RateLimitExceeded(_) => 80,
+            ContractError::SelfReferral => 81,
+            ContractError::InvalidTimezone(_) => 82,
+            ContractError::EventFull { .. } => 83,
+            ContractError::EventNotFull => 84,
+            ContractError::AlreadyWaitlisted => 85,
+            ContractError::NotWaitlisted => 86,
+            ContractError::NotYourTurn { .. } => 87,
+            ContractError::TicketNotFound { .. } => 88,
+            ContractError::AlreadyHasTicket { .. } => 89,
+            ContractError::SubscriptionRequired => 90,
+            ContractError::SubscriptionPlanNotFound { .. } => 91,
+            ContractError::SubscriptionFee(_) => 92,
+            ContractError::InvalidSubscriptionPlan => 93,
+            ContractError::EventQuotaReached { .. } => 94,
+            ContractError::CapacityAboveLimit { .. } => 95,
+            ContractError::CouncilApprovalRequired => 96,
+            ContractError::NoEventCouncil => 97,
+            ContractError::InvalidCouncil => 98,
+            ContractError::NotCouncilMember => 99,
+            ContractError::ProposalNotFound { .. } => 100,
+            ContractError::AlreadyApproved => 101,
+            ContractError::InvalidApprovalExpiry => 102,
+            ContractError::InvalidCollectionInfo => 103,
+            ContractError::TemplateNotFound { .. } => 104,
+            ContractError::BadgeExported { .. } => 105,
+            ContractError::InvalidOpenClaimWindow(_) => 106,
+            ContractError::OpenClaimClosed => 107,
+            ContractError::MemoTooLong(_) => 108,
+            ContractError::EventArchived => 109,
+            ContractError::TooManyEvents(_) => 110,
+            ContractError::NoRole => 111,
+            ContractError::InvalidChallengePeriod(_) => 112,
+            ContractError::BadgeProvisional { .. } => 113,
+            ContractError::NotProvisional => 114,
+            ContractError::ClaimChallenged => 115,
+            ContractError::RemoteRootNotFound { .. } => 116,
+            ContractError::InvalidRemoteProof => 117,
+            ContractError::TooManyAttendees(_) => 118,
+            ContractError::RecoveryNotFound => 119,
+            ContractError::RecoveryTimelocked { .. } => 120,
+            ContractError::KioskClosed => 121,
+            ContractError::KioskTierMismatch { .. } => 122,
+            ContractError::KioskNotFound => 123,
+            ContractError::MintQuotaExhausted => 124,
+            ContractError::UnresolvedName(_) => 125,
+            ContractError::InvalidForeignAddress(_) => 126,
+            ContractError::PrivateEvent => 127,
+            ContractError::EventNotPrivate => 128,
+            ContractError::MigrationInProgress => 129,
+            ContractError::NoMigrationInProgress => 130,
+            ContractError::UnknownTicketCollection => 131,
+            ContractError::UnknownTicketToken => 132,
+            ContractError::WrongTicketAmount { .. } => 133,
+            ContractError::NoRandomnessProxy => 134,
+            ContractError::RaffleExists => 135,
+            ContractError::RaffleNotDrawing => 136,
+            ContractError::TooManySponsors(_) => 137,
+            ContractError::NoSponsorshipFunds => 138,
+            ContractError::EventNotSuccessful => 139,
+            ContractError::SponsorshipLocked => 140,
+            ContractError::EventNotCancelled => 141,
+            ContractError::InvalidPublishTime => 142,
+            ContractError::InvalidBadgeAttribute(_) => 143,
+            ContractError::NoGasBudget => 144,
+            ContractError::GasBudgetExhausted => 145,
+            ContractError::BadgeExpired => 146,
+            ContractError::AlreadyRedeemed => 147,
+            ContractError::ClaimSecretInactive => 148,
+            ContractError::InvalidClaimSecretWindow(_) => 149,
+            ContractError::TooManyClaimSecrets(_) => 150,
+            ContractError::InvalidKeySignature => 151,
+            ContractError::TooManyActiveEvents(_) => 152,
+            ContractError::MintingPaused => 153,
+            ContractError::BadgeDataErased => 154,
+            ContractError::FeeRequired(_) => 155,
+            ContractError::InvalidFeeDiscounts(_) => 156,
+            ContractError::InvalidStreak(_) => 157,
+            ContractError::InvalidAchievement(_) => 158,
+            ContractError::AchievementIncomplete(_) => 159,
+            ContractError::TooManyApprovals(_) => 160,
+            ContractError::TooManyRoles(_) => 161,
+            ContractError::TooManyKiosks(_) => 162,
+            ContractError::TooManyOccurrences(_) => 163,
+            ContractError::WaitlistFull(_) => 164,
+            ContractError::TooManyFeeDenoms(_) => 165,
+            ContractError::TooManyStreaks(_) => 166,
+            ContractError::EventNotFrozen => 167,
+            ContractError::NoAttendees => 168,
+            ContractError::AirdropExists => 169,
+            ContractError::AirdropFunds(_) => 170,
+            ContractError::InvalidLocation => 171,
+            ContractError::VenueTooLong(_) => 172,
+            ContractError::ReservedEventName => 173,
+            ContractError::TooManyBadgeClasses(_) => 174,
+            ContractError::NestedBadgeClass => 175,
+            ContractError::BadgeMetadataFrozen => 176,
+            ContractError::InvalidDeploymentInfo => 177,
+            ContractError::TooManyHooks(_) => 178,
+            ContractError::UnknownReplyId(_) => 179,
+            ContractError::BatchPending(_) => 180,
+            ContractError::NoPendingBatch(_) => 181,
+            ContractError::NotAHolder => 182,
+            ContractError::InvalidNickname(_) => 183,
+            ContractError::ExtensionTooLarge(_) => 184,
+            ContractError::MigrationAdminRequired => 185,
+            ContractError::InvalidRateLimit => 186,
+            ContractError::UnevenBatchFee(_) => 187,
+            ContractError::InvalidPerkId { .. } => 188,
+            ContractError::InvalidTicketAmount => 189,
+            ContractError::NoRaffleWinners => 190,
+            ContractError::InvalidRandomness => 191,
+            ContractError::InvalidGasAllowance => 192,
+        }
+    }
 }
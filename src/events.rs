@@ -0,0 +1,249 @@
+//! Naming convention for what execute handlers emit, so indexers have a stable interface.
+//!
+//! Every handler emits exactly one custom event, created with [`action_event`]. Its type is
+//! one of the action names below (`wasm-<action>` on chain). It always starts with the
+//! `action` and `sender` attributes, followed by the identifiers of the entities it touched,
+//! using the keys below. Handler-specific details (eg. `score`) come last.
+//!
+//! Mints, registrations and revocations end with a `payload` attribute as well, repeating
+//! the identifiers as one JSON document (see [`Payload`]) for indexers that don't parse
+//! each action.
+
+use cosmwasm_std::{to_vec, Addr, Event, StdError, StdResult};
+use serde::Serialize;
+
+// actions, used as the event type and the value of the `action` attribute
+pub const REGISTER_EVENT: &str = "register-event";
+pub const MINT_BADGE: &str = "mint-badge";
+pub const BURN_BADGE: &str = "burn-badge";
+pub const REDEEM_BADGE: &str = "redeem-badge";
+pub const TRANSFER_BADGE: &str = "transfer-badge";
+pub const BIND_BADGE_KEY: &str = "bind-badge-key";
+pub const UNBIND_BADGE_KEY: &str = "unbind-badge-key";
+pub const ERASE_MY_DATA: &str = "erase-my-data";
+pub const FREEZE_BADGE_METADATA: &str = "freeze-badge-metadata";
+pub const SET_MINT_HOOK: &str = "set-mint-hook";
+pub const MINT_BADGES: &str = "mint-badges";
+pub const SET_NICKNAME: &str = "set-nickname";
+/// Emitted in the reply to a fire-and-forget mint hook that failed
+pub const MINT_HOOK_FAILED: &str = "mint-hook-failed";
+pub const APPROVE: &str = "approve";
+pub const REVOKE: &str = "revoke";
+pub const APPROVE_ALL: &str = "approve-all";
+pub const REVOKE_ALL: &str = "revoke-all";
+pub const UPDATE_BADGE: &str = "update-badge";
+pub const SET_BADGE_IMAGE: &str = "set-badge-image";
+pub const RESERVE_BADGE: &str = "reserve-badge";
+pub const ADD_CLAIM_CODES: &str = "add-claim-codes";
+pub const CHECK_IN: &str = "check-in";
+pub const CHECK_OUT: &str = "check-out";
+pub const COMMIT_KIOSK_NONCE: &str = "commit-kiosk-nonce";
+pub const ROTATE_CLAIM_SECRET: &str = "rotate-claim-secret";
+pub const REGISTER_KIOSK: &str = "register-kiosk";
+pub const REMOVE_KIOSK: &str = "remove-kiosk";
+pub const ADD_ORGANIZER: &str = "add-organizer";
+pub const REMOVE_ORGANIZER: &str = "remove-organizer";
+pub const SET_SUBSCRIPTION_PLAN: &str = "set-subscription-plan";
+pub const REMOVE_SUBSCRIPTION_PLAN: &str = "remove-subscription-plan";
+pub const ADD_BADGE_CLASS: &str = "add-badge-class";
+pub const SAVE_EVENT_TEMPLATE: &str = "save-event-template";
+pub const REMOVE_EVENT_TEMPLATE: &str = "remove-event-template";
+pub const EXPORT_BADGE: &str = "export-badge";
+pub const SUBSCRIBE: &str = "subscribe";
+pub const APPROVE_EVENT: &str = "approve-event";
+pub const UPDATE_EVENT_METADATA: &str = "update-event-metadata";
+pub const FREEZE_EVENT: &str = "freeze-event";
+pub const CLOSE_EVENT_EARLY: &str = "close-event-early";
+pub const CANCEL_EVENT: &str = "cancel-event";
+pub const CONTINUE_REFUNDS: &str = "continue-refunds";
+pub const FINALIZE_EVENT: &str = "finalize-event";
+pub const ARCHIVE_EVENT: &str = "archive-event";
+pub const SET_TRANSFERABLE: &str = "set-transferable";
+pub const SET_MINTING_PAUSED: &str = "set-minting-paused";
+pub const SET_EVENT_COUNCIL: &str = "set-event-council";
+pub const PROPOSE_EVENT_ACTION: &str = "propose-event-action";
+pub const APPROVE_EVENT_ACTION: &str = "approve-event-action";
+pub const ISSUE_TICKET: &str = "issue-ticket";
+pub const TRANSFER_TICKET: &str = "transfer-ticket";
+pub const SET_CAPACITY: &str = "set-capacity";
+pub const SET_OPEN_CLAIM: &str = "set-open-claim";
+pub const SET_CHALLENGE_PERIOD: &str = "set-challenge-period";
+pub const CHALLENGE_CLAIM: &str = "challenge-claim";
+pub const REVOKE_BADGE: &str = "revoke-badge";
+pub const JOIN_WAITLIST: &str = "join-waitlist";
+pub const LEAVE_WAITLIST: &str = "leave-waitlist";
+pub const ADD_CO_OWNER: &str = "add-co-owner";
+pub const REMOVE_CO_OWNER: &str = "remove-co-owner";
+pub const ADD_MINTER: &str = "add-minter";
+pub const REMOVE_MINTER: &str = "remove-minter";
+pub const PRUNE_MINTERS: &str = "prune-minters";
+pub const CLEANUP: &str = "cleanup";
+pub const CONTINUE_MIGRATION: &str = "continue-migration";
+pub const GRANT_ROLE: &str = "grant-role";
+pub const REVOKE_ROLE: &str = "revoke-role";
+pub const ADD_OCCURRENCE: &str = "add-occurrence";
+pub const SET_EVENT_VENUE: &str = "set-event-venue";
+pub const SET_EVENT_EXTENSION: &str = "set-event-extension";
+pub const SET_TRANSLATION: &str = "set-translation";
+pub const REMOVE_TRANSLATION: &str = "remove-translation";
+pub const RATE_EVENT: &str = "rate-event";
+pub const FLAG_EVENT: &str = "flag-event";
+pub const CREATE_SERIES: &str = "create-series";
+pub const ADD_TO_SERIES: &str = "add-to-series";
+pub const SET_SERIES_STREAK: &str = "set-series-streak";
+pub const DEFINE_ACHIEVEMENT: &str = "define-achievement";
+pub const REMOVE_ACHIEVEMENT: &str = "remove-achievement";
+pub const CLAIM_ACHIEVEMENT: &str = "claim-achievement";
+pub const SET_CW20_REWARD: &str = "set-cw20-reward";
+pub const SET_NFT_TICKET_COLLECTION: &str = "set-nft-ticket-collection";
+pub const SET_CW20_TICKET_TOKEN: &str = "set-cw20-ticket-token";
+pub const FUND_RAFFLE: &str = "fund-raffle";
+pub const DRAW_RAFFLE: &str = "draw-raffle";
+pub const WITHDRAW_REWARDS: &str = "withdraw-rewards";
+pub const SPONSOR_EVENT: &str = "sponsor-event";
+pub const RELEASE_SPONSORSHIPS: &str = "release-sponsorships";
+pub const WITHDRAW_SPONSORSHIP: &str = "withdraw-sponsorship";
+pub const FUND_GAS_BUDGET: &str = "fund-gas-budget";
+pub const GRANT_CLAIM_GAS: &str = "grant-claim-gas";
+pub const WITHDRAW_GAS_BUDGET: &str = "withdraw-gas-budget";
+pub const REFUND_DEPOSIT: &str = "refund-deposit";
+pub const FORFEIT_DEPOSIT: &str = "forfeit-deposit";
+pub const SWEEP_FUNDS: &str = "sweep-funds";
+pub const FORCE_TRANSFER_EVENT: &str = "force-transfer-event";
+pub const COMPLETE_EVENT_RECOVERY: &str = "complete-event-recovery";
+pub const CANCEL_EVENT_RECOVERY: &str = "cancel-event-recovery";
+pub const CREATE_AIRDROP: &str = "create-airdrop";
+pub const SET_REMOTE_ROOT: &str = "set-remote-root";
+pub const VERIFY_REMOTE_BADGE: &str = "verify-remote-badge";
+pub const MINT_FOREIGN_BADGE: &str = "mint-foreign-badge";
+pub const MINT_PRIVATE_BADGE: &str = "mint-private-badge";
+pub const UPDATE_CONFIG: &str = "update-config";
+pub const SET_ORGANIZER_EVENT_LIMIT: &str = "set-organizer-event-limit";
+pub const SET_DEPLOYMENT_INFO: &str = "set-deployment-info";
+pub const SET_FEE: &str = "set-fee";
+pub const SET_FEE_DISCOUNTS: &str = "set-fee-discounts";
+pub const UPDATE_OWNERSHIP: &str = "update-ownership";
+
+/// The privileged actions recorded in the audit log: edits, revocations, ownership and
+/// config changes
+pub const AUDITED: &[&str] = &[
+    UPDATE_BADGE,
+    SET_BADGE_IMAGE,
+    ADD_CLAIM_CODES,
+    ADD_ORGANIZER,
+    REMOVE_ORGANIZER,
+    SET_SUBSCRIPTION_PLAN,
+    REMOVE_SUBSCRIPTION_PLAN,
+    APPROVE_EVENT,
+    UPDATE_EVENT_METADATA,
+    FREEZE_EVENT,
+    CLOSE_EVENT_EARLY,
+    CANCEL_EVENT,
+    SET_TRANSFERABLE,
+    SET_MINTING_PAUSED,
+    SET_EVENT_COUNCIL,
+    SET_CAPACITY,
+    SET_OPEN_CLAIM,
+    SET_CHALLENGE_PERIOD,
+    CHALLENGE_CLAIM,
+    REVOKE_BADGE,
+    ADD_CO_OWNER,
+    REMOVE_CO_OWNER,
+    ADD_MINTER,
+    REMOVE_MINTER,
+    GRANT_ROLE,
+    REVOKE_ROLE,
+    REGISTER_KIOSK,
+    REMOVE_KIOSK,
+    ADD_OCCURRENCE,
+    SET_TRANSLATION,
+    REMOVE_TRANSLATION,
+    SET_CW20_REWARD,
+    SET_NFT_TICKET_COLLECTION,
+    SET_CW20_TICKET_TOKEN,
+    FUND_RAFFLE,
+    FUND_GAS_BUDGET,
+    WITHDRAW_GAS_BUDGET,
+    WITHDRAW_REWARDS,
+    FORFEIT_DEPOSIT,
+    SWEEP_FUNDS,
+    FORCE_TRANSFER_EVENT,
+    COMPLETE_EVENT_RECOVERY,
+    CANCEL_EVENT_RECOVERY,
+    SET_REMOTE_ROOT,
+    UPDATE_CONFIG,
+    SET_ORGANIZER_EVENT_LIMIT,
+    SET_DEPLOYMENT_INFO,
+    SET_FEE,
+    SET_FEE_DISCOUNTS,
+    UPDATE_OWNERSHIP,
+];
+
+// attribute keys
+pub const ACTION: &str = "action";
+pub const SENDER: &str = "sender";
+pub const EVENT: &str = "event";
+pub const ATTENDEE: &str = "attendee";
+pub const OCCURRENCE: &str = "occurrence";
+pub const SERIES: &str = "series";
+pub const CO_OWNER: &str = "co_owner";
+pub const MINTER: &str = "minter";
+pub const ORGANIZER: &str = "organizer";
+pub const RECIPIENT: &str = "recipient";
+pub const HOLDER: &str = "holder";
+pub const PLAN: &str = "plan";
+pub const PROPOSAL: &str = "proposal";
+pub const SPENDER: &str = "spender";
+pub const OPERATOR: &str = "operator";
+pub const KIOSK: &str = "kiosk";
+pub const TEMPLATE: &str = "template";
+pub const GRANTEE: &str = "grantee";
+pub const PAYLOAD: &str = "payload";
+
+/// Version of the `payload` document, raised on incompatible changes
+pub const PAYLOAD_VERSION: u32 = 1;
+
+/// The `payload` attribute. Identifiers the action didn't touch are left out
+#[derive(Serialize, Debug)]
+pub struct Payload<'a> {
+    pub v: u32,
+    pub action: &'a str,
+    pub sender: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendee: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurrence: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spender: Option<&'a str>,
+}
+
+/// Starts the event for an action, with the `action` and `sender` attributes set
+pub fn action_event(action: &str, sender: &Addr) -> Event {
+    Event::new(action)
+        .add_attribute(ACTION, action)
+        .add_attribute(SENDER, sender)
+}
+
+/// Appends the `payload` attribute, built from the identifiers already on the event.
+/// Call it last, once all of them are set
+pub fn add_payload(ev: Event) -> StdResult<Event> {
+    let get = |key: &str| {
+        ev.attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+    };
+    let payload = Payload {
+        v: PAYLOAD_VERSION,
+        action: &ev.ty,
+        sender: get(SENDER).unwrap_or_default(),
+        event: get(EVENT),
+        attendee: get(ATTENDEE),
+        occurrence: get(OCCURRENCE),
+        spender: get(SPENDER),
+    };
+    let json = String::from_utf8(to_vec(&payload)?).map_err(StdError::from)?;
+    Ok(ev.add_attribute(PAYLOAD, json))
+}
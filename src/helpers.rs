@@ -0,0 +1,456 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Coin, CosmosMsg, CustomMsg, CustomQuery, QuerierWrapper, QueryRequest,
+    StdResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::msg::{
+    BadgeCountResponse, CheckGateResponse, CompletedSeriesResponse, ExecuteMsg, GateCondition,
+    GetEventResponse, HasBadgeResponse, ListMyBadgesResponse, QueryMsg, SeriesProgressResponse,
+};
+
+/// PoapContract is a wrapper around Addr that provides a lot of helpers
+/// for working with this contract from other contracts.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoapContract(pub Addr);
+
+impl PoapContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<ExecuteMsg>, C: CustomMsg>(&self, msg: T) -> StdResult<CosmosMsg<C>> {
+        self.call_with_funds(msg, vec![])
+    }
+
+    pub fn call_with_funds<T: Into<ExecuteMsg>, C: CustomMsg>(
+        &self,
+        msg: T,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        let msg = to_binary(&msg.into())?;
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into(),
+            msg,
+            funds,
+        }
+        .into())
+    }
+
+    fn query<T: serde::de::DeserializeOwned, Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        msg: &QueryMsg,
+    ) -> StdResult<T> {
+        let query = QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: self.addr().into(),
+            msg: to_binary(msg)?,
+        });
+        querier.query(&query)
+    }
+
+    pub fn event<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        name: impl Into<String>,
+    ) -> StdResult<GetEventResponse> {
+        let msg = QueryMsg::GetEvent { name: name.into() };
+        self.query(querier, &msg)
+    }
+
+    pub fn has_badge<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        event: impl Into<String>,
+        attendee: impl Into<String>,
+        only_valid: bool,
+    ) -> StdResult<bool> {
+        let msg = QueryMsg::HasBadge {
+            event: event.into(),
+            attendee: attendee.into(),
+            only_valid,
+            salt: None,
+        };
+        let res: HasBadgeResponse = self.query(querier, &msg)?;
+        Ok(res.has_badge)
+    }
+
+    /// Number of badges the attendee holds across all events, expired ones included
+    pub fn badge_count<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        attendee: impl Into<String>,
+    ) -> StdResult<u32> {
+        let msg = QueryMsg::BadgeCount {
+            attendee: attendee.into(),
+        };
+        let res: BadgeCountResponse = self.query(querier, &msg)?;
+        Ok(res.total)
+    }
+
+    pub fn badges<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        attendee: impl Into<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<ListMyBadgesResponse> {
+        let msg = QueryMsg::ListMyBadges {
+            attendee: attendee.into(),
+            start_after,
+            limit,
+            reverse: false,
+        };
+        self.query(querier, &msg)
+    }
+
+    pub fn series_progress<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        series: impl Into<String>,
+        attendee: impl Into<String>,
+        only_valid: bool,
+    ) -> StdResult<SeriesProgressResponse> {
+        let msg = QueryMsg::SeriesProgress {
+            series: series.into(),
+            attendee: attendee.into(),
+            only_valid,
+        };
+        self.query(querier, &msg)
+    }
+
+    /// True if the attendee has badges for at least min_count events of the series
+    pub fn completed_series<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        series: impl Into<String>,
+        attendee: impl Into<String>,
+        min_count: u32,
+    ) -> StdResult<bool> {
+        let msg = QueryMsg::CompletedSeries {
+            series: series.into(),
+            attendee: attendee.into(),
+            min_count,
+            only_valid: true,
+        };
+        let res: CompletedSeriesResponse = self.query(querier, &msg)?;
+        Ok(res.completed)
+    }
+
+    /// True if the attendee meets all the conditions, with expired badges ignored
+    pub fn check_gate<Q: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<Q>,
+        attendee: impl Into<String>,
+        conditions: Vec<GateCondition>,
+    ) -> StdResult<bool> {
+        let msg = QueryMsg::CheckGate {
+            attendee: attendee.into(),
+            conditions,
+            only_valid: true,
+        };
+        let res: CheckGateResponse = self.query(querier, &msg)?;
+        Ok(res.passed)
+    }
+}
+
+/// Attendance checks on a deployment of this contract, straight from the querier of a consumer
+/// contract: `deps.querier.has_badge(&poap, "Hacker House", &info.sender, true)?`
+pub trait PoapQuerier {
+    fn has_badge(
+        &self,
+        poap: &Addr,
+        event: impl Into<String>,
+        attendee: impl Into<String>,
+        only_valid: bool,
+    ) -> StdResult<bool>;
+
+    fn badge_count(&self, poap: &Addr, attendee: impl Into<String>) -> StdResult<u32>;
+
+    fn check_gate(
+        &self,
+        poap: &Addr,
+        attendee: impl Into<String>,
+        conditions: Vec<GateCondition>,
+    ) -> StdResult<bool>;
+}
+
+impl<'a, Q: CustomQuery> PoapQuerier for QuerierWrapper<'a, Q> {
+    fn has_badge(
+        &self,
+        poap: &Addr,
+        event: impl Into<String>,
+        attendee: impl Into<String>,
+        only_valid: bool,
+    ) -> StdResult<bool> {
+        PoapContract(poap.clone()).has_badge(self, event, attendee, only_valid)
+    }
+
+    fn badge_count(&self, poap: &Addr, attendee: impl Into<String>) -> StdResult<u32> {
+        PoapContract(poap.clone()).badge_count(self, attendee)
+    }
+
+    fn check_gate(
+        &self,
+        poap: &Addr,
+        attendee: impl Into<String>,
+        conditions: Vec<GateCondition>,
+    ) -> StdResult<bool> {
+        PoapContract(poap.clone()).check_gate(self, attendee, conditions)
+    }
+}
+
+/// Id of an event on a contract instantiated with `scoped_event_names`: the organizer's
+/// address and the event name, separated by a slash
+pub fn scoped_event_id(owner: &str, name: &str) -> String {
+    format!("{}/{}", owner, name)
+}
+
+/// Separates an event name from the name of one of its badge classes
+pub const CLASS_SEPARATOR: &str = "::";
+
+/// Name of the event holding the badges of a class of an event, like "DevCon::Day 1"
+pub fn badge_class_id(event: &str, class: &str) -> String {
+    format!("{}{}{}", event, CLASS_SEPARATOR, class)
+}
+
+/// The message a key signs to be bound to a badge: the sha256 of
+/// "bind-badge-key:{contract}:{event}:{holder}". Secp256k1 keys sign this hash, ed25519 keys
+/// sign its 32 bytes
+pub fn badge_key_challenge(contract: &str, event: &str, holder: &str) -> [u8; 32] {
+    let message = format!("bind-badge-key:{}:{}:{}", contract, event, holder);
+    Sha256::digest(message.as_bytes()).into()
+}
+
+/// Id of a badge in cw721 queries: the event name and the attendee address, separated by a
+/// slash. Addresses never contain one, so event names may
+pub fn token_id(event: &str, attendee: impl std::fmt::Display) -> String {
+    format!("{}/{}", event, attendee)
+}
+
+/// Splits a token id into the event name and the attendee address
+pub fn parse_token_id(token_id: &str) -> Option<(&str, &str)> {
+    token_id
+        .rsplit_once('/')
+        .filter(|(event, attendee)| !event.is_empty() && !attendee.is_empty())
+}
+
+/// Leaf of the attendee merkle tree of an archived event: the sha256 of the address
+pub fn attendee_leaf(attendee: &str) -> [u8; 32] {
+    Sha256::digest(attendee.as_bytes()).into()
+}
+
+/// Leaf of an airdrop merkle tree, as cw20-merkle-airdrop builds it: the sha256 of the address
+/// followed by the amount
+pub fn airdrop_leaf(attendee: &str, amount: Uint128) -> [u8; 32] {
+    Sha256::digest(format!("{}{}", attendee, amount).as_bytes()).into()
+}
+
+// pairs are hashed in sorted order, so proofs don't need to say which side a sibling is on
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    Sha256::new().chain(first).chain(second).finalize().into()
+}
+
+fn merkle_level(nodes: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => hash_pair(a, b),
+            // an odd node out is carried up as is
+            _ => pair[0],
+        })
+        .collect()
+}
+
+/// Root of the merkle tree over the leaves (in the order given), None if there are none
+pub fn merkle_root(mut leaves: Vec<[u8; 32]>) -> Option<[u8; 32]> {
+    while leaves.len() > 1 {
+        leaves = merkle_level(&leaves);
+    }
+    leaves.first().copied()
+}
+
+/// The siblings on the path from the leaf at `index` to the root, as checked by
+/// [`verify_merkle_proof`]
+pub fn merkle_proof(mut leaves: Vec<[u8; 32]>, mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = vec![];
+    while leaves.len() > 1 {
+        if let Some(sibling) = leaves.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        leaves = merkle_level(&leaves);
+        index /= 2;
+    }
+    proof
+}
+
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    &computed == root
+}
+
+/// A feegrant MsgGrantAllowance from `granter`, paying the fees of the wasm executions of
+/// `grantee` with up to `spend_limit` until `expiration`. Chains need the feegrant module
+pub fn fee_grant_msg<C: CustomMsg>(
+    granter: &Addr,
+    grantee: &Addr,
+    spend_limit: &Coin,
+    expiration: Timestamp,
+) -> CosmosMsg<C> {
+    let coin = [
+        proto_bytes(1, spend_limit.denom.as_bytes()),
+        proto_bytes(2, spend_limit.amount.to_string().as_bytes()),
+    ]
+    .concat();
+    let expiration = proto_varint(1, expiration.seconds());
+    let basic = [proto_bytes(1, &coin), proto_bytes(2, &expiration)].concat();
+    let allowed = [
+        proto_bytes(1, &proto_any(BASIC_ALLOWANCE, &basic)),
+        proto_bytes(2, MSG_EXECUTE_CONTRACT.as_bytes()),
+    ]
+    .concat();
+    let value = [
+        proto_bytes(1, granter.as_bytes()),
+        proto_bytes(2, grantee.as_bytes()),
+        proto_bytes(3, &proto_any(ALLOWED_MSG_ALLOWANCE, &allowed)),
+    ]
+    .concat();
+    CosmosMsg::Stargate {
+        type_url: MSG_GRANT_ALLOWANCE.to_string(),
+        value: Binary(value),
+    }
+}
+
+const MSG_GRANT_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+const ALLOWED_MSG_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance";
+const BASIC_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+const MSG_EXECUTE_CONTRACT: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+
+// just enough protobuf to encode the feegrant messages, which only use strings, bytes and
+// unsigned integers
+
+fn proto_any(type_url: &str, value: &[u8]) -> Vec<u8> {
+    [proto_bytes(1, type_url.as_bytes()), proto_bytes(2, value)].concat()
+}
+
+fn proto_bytes(field: u64, bytes: &[u8]) -> Vec<u8> {
+    let mut out = varint(field << 3 | 2);
+    out.extend(varint(bytes.len() as u64));
+    out.extend_from_slice(bytes);
+    out
+}
+
+fn proto_varint(field: u64, value: u64) -> Vec<u8> {
+    let mut out = varint(field << 3);
+    out.extend(varint(value));
+    out
+}
+
+fn varint(mut value: u64) -> Vec<u8> {
+    let mut out = vec![];
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_binary;
+
+    #[test]
+    fn call_builds_wasm_execute() {
+        let poap = PoapContract(Addr::unchecked("poap"));
+        let msg: CosmosMsg = poap
+            .call(ExecuteMsg::BurnBadge {
+                event: "Hacker House".to_string(),
+            })
+            .unwrap();
+        match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => {
+                assert_eq!(contract_addr, "poap");
+                assert!(funds.is_empty());
+                let parsed: ExecuteMsg = from_binary(&msg).unwrap();
+                assert_eq!(
+                    parsed,
+                    ExecuteMsg::BurnBadge {
+                        event: "Hacker House".to_string()
+                    }
+                );
+            }
+            _ => panic!("unexpected message: {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn token_ids_round_trip() {
+        let id = token_id("DAO/Meetup", Addr::unchecked("alice"));
+        assert_eq!(id, "DAO/Meetup/alice");
+        assert_eq!(parse_token_id(&id), Some(("DAO/Meetup", "alice")));
+        assert_eq!(parse_token_id("alice"), None);
+        assert_eq!(parse_token_id("/alice"), None);
+    }
+
+    #[test]
+    fn merkle_proofs_verify() {
+        assert_eq!(merkle_root(vec![]), None);
+        let leaves: Vec<_> = ["alice", "bob", "carl", "dave", "erin"]
+            .iter()
+            .map(|addr| attendee_leaf(addr))
+            .collect();
+        let root = merkle_root(leaves.clone()).unwrap();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_proof(leaves.clone(), i);
+            assert!(verify_merkle_proof(&root, *leaf, &proof));
+            assert!(!verify_merkle_proof(
+                &root,
+                attendee_leaf("mallory"),
+                &proof
+            ));
+        }
+        let single = merkle_root(vec![leaves[0]]).unwrap();
+        assert!(verify_merkle_proof(&single, leaves[0], &[]));
+    }
+
+    #[test]
+    fn fee_grants_are_protobuf_encoded() {
+        assert_eq!(varint(1), vec![1]);
+        assert_eq!(varint(300), vec![0xac, 0x02]);
+        assert_eq!(proto_bytes(2, b"100"), b"\x12\x03100".to_vec());
+
+        let limit = Coin::new(5000, "ujuno");
+        let expiration = Timestamp::from_seconds(1_700_000_000);
+        let msg: CosmosMsg = fee_grant_msg(
+            &Addr::unchecked("poap"),
+            &Addr::unchecked("alice"),
+            &limit,
+            expiration,
+        );
+        match msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, MSG_GRANT_ALLOWANCE);
+                assert!(value.starts_with(b"\x0a\x04poap\x12\x05alice\x1a"));
+                let contains = |needle: &[u8]| value.windows(needle.len()).any(|w| w == needle);
+                assert!(contains(ALLOWED_MSG_ALLOWANCE.as_bytes()));
+                assert!(contains(b"\x0a\x05ujuno\x12\x045000"));
+                assert!(contains(&proto_varint(1, 1_700_000_000)));
+                assert!(contains(MSG_EXECUTE_CONTRACT.as_bytes()));
+            }
+            _ => panic!("unexpected message: {:?}", msg),
+        }
+    }
+}
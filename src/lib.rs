@@ -1,6 +1,30 @@
+#[cfg(feature = "contract")]
+mod cache;
+#[cfg(feature = "contract")]
 pub mod contract;
 mod error;
+pub mod events;
+pub mod helpers;
 pub mod msg;
+#[cfg(all(feature = "contract", any(test, feature = "test-utils")))]
+pub mod multitest;
+pub mod ownership;
 pub mod state;
 
 pub use crate::error::ContractError;
+
+// With the library feature the entry points are not exported, and the contract is meant to be
+// embedded in another one. Expose the full API at the crate root for convenience.
+#[cfg(all(feature = "library", feature = "contract"))]
+pub use crate::contract::*;
+#[cfg(feature = "library")]
+pub use crate::{
+    helpers::{
+        airdrop_leaf, attendee_leaf, badge_class_id, badge_key_challenge, fee_grant_msg,
+        merkle_proof, merkle_root, parse_token_id, scoped_event_id, token_id, verify_merkle_proof,
+        PoapContract, PoapQuerier,
+    },
+    msg::*,
+    ownership::*,
+    state::*,
+};
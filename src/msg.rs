@@ -1,85 +1,2699 @@
-use crate::state::EventData;
-use schemars::JsonSchema;
+use cosmwasm_std::{Attribute, Binary, Coin, Decimal, Timestamp, Uint128};
+use cw_utils::Expiration;
+use std::collections::BTreeMap;
+
+use crate::ownership::{Action, Ownership};
+use crate::state::{
+    AddressFormat, Airdrop, ArchivedEvent, AttendanceRoot, BadgeAttribute, BadgeFreezePolicy,
+    BoundKey, Change, ClaimWindow, CollectionInfo, DeploymentInfo, EventAction, EventCouncil,
+    EventData, EventMetadata, EventRecovery, EventSort, EventStatus, EventTemplate, FeeDiscount,
+    FeeKind, GasBudget, GeoPoint, HookPolicy, KeyAlgorithm, Member, MetadataChange, MigrationStep,
+    MintFlow, Raffle, RecentMint, RegistryMode, Role, SeriesStreak, SubscriptionPlan, Tier,
+    Translation, ValidationLimits,
+};
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    /// Contract owner (admin), allowed to update the config and sweep stray funds.
+    /// Defaults to the instantiator
+    pub admin: Option<String>,
+    /// Bounds on event metadata. Defaults to ValidationLimits::default()
+    pub limits: Option<ValidationLimits>,
+    /// Also accept plain http:// URLs. Only meant for testnets and local development
+    #[serde(default)]
+    pub allow_insecure_urls: bool,
+    /// If set, registering an event requires escrowing this deposit until the event is over
+    pub registration_deposit: Option<Coin>,
+    /// Curated mode: new events must be approved by the admin before badges can be minted
+    #[serde(default)]
+    pub require_approval: bool,
+    /// Who may register events and series. This can't be changed later
+    #[serde(default)]
+    pub registry_mode: RegistryMode,
+    /// Event names are unique per organizer: events are addressed by `<owner>/<name>`, as
+    /// built by `scoped_event_id`. This can't be changed later
+    #[serde(default)]
+    pub scoped_event_names: bool,
+    /// Collection name and symbol for NFT wallets. Defaults to "DSRV POAP" and "POAP"
+    #[serde(default)]
+    pub collection: Option<CollectionInfo>,
+    /// Name, description and branding image explorers label this deployment with
+    #[serde(default)]
+    pub deployment: Option<DeploymentInfo>,
+}
+
+/// Called by the chain, eg. by a clock module, without a sender
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    /// Freeze the events whose claim deadline passed and emit their final stats.
+    /// At most 20 events are finalized per call, the rest on the next ones
+    EndOfBlock {},
+}
+
+/// Checks the stored contract name and migrates the layout. Badges are migrated in batches:
+/// if they don't all fit in this transaction, call ContinueMigration until MigrationStatus
+/// shows no step left. Other messages are rejected until then
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {
+    /// Admin of a contract migrated from a version that stored no config (like the first
+    /// release), which then gets the default config. Ignored otherwise
+    #[serde(default)]
+    pub admin: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    RegisterEvent(RegisterEventMsg),
+    /// Registers a new event with the image, description, metadata and settings of an event
+    /// the sender owns, so recurring meetups don't re-enter them every time. Badge expiry and
+    /// claim deadlines keep the same offset from the new end time. Reward pools are not
+    /// copied, and the usual registration rules and deposit apply
+    CloneEvent {
+        source: String,
+        name: String,
+        start_time: u64,
+        end_time: u64,
+    },
+    /// Add a separately mintable badge class to the event (eg. "Day 1", "Workshop A"),
+    /// registered as the event "{event}::{class}" with the event's settings and owners, but
+    /// its own image, capacity and time window. The event's staff can mint it. Event owners
+    /// only, the registration fee and deposit apply
+    AddBadgeClass {
+        event: String,
+        class: String,
+        image: String,
+        capacity: Option<u32>,
+        start_time: u64,
+        end_time: u64,
+    },
+    /// Add or replace one of the sender's event templates
+    SaveEventTemplate {
+        name: String,
+        template: EventTemplate,
+    },
+    RemoveEventTemplate {
+        name: String,
+    },
+    /// Registers an event from one of the sender's templates. The usual registration rules
+    /// and deposit apply
+    RegisterFromTemplate {
+        template: String,
+        name: String,
+        start_time: u64,
+        end_time: u64,
+    },
+    MintBadge(MintBadgeMsg),
+    /// Mint the attendee's badge for several events at once (eg. each day of a conference),
+    /// checking permissions and mint windows per event. Events that fail are skipped, the
+    /// others are minted. A MintForEventsResponse is returned as data
+    MintForEvents {
+        attendee: String,
+        events: Vec<String>,
+    },
+    /// Mint the badges of up to 1000 attendees of an event, checking permissions and the mint
+    /// window per attendee. Attendees that fail are skipped. In partial mode only the
+    /// configured `batch_mint_limit` of badges is minted, the rest with ContinueBatch. A
+    /// MintBadgesResponse is returned as data
+    MintBadges {
+        event: String,
+        attendees: Vec<String>,
+        #[serde(default)]
+        partial: bool,
+    },
+    /// Mint the next badges of the sender's partial MintBadges batch for the event
+    ContinueBatch {
+        event: String,
+    },
+    /// Any owner of the event may add another co-owner with equal rights
+    AddCoOwner {
+        event: String,
+        co_owner: String,
+    },
+    /// Any owner of the event may remove a co-owner (but not the original owner)
+    RemoveCoOwner {
+        event: String,
+        co_owner: String,
+    },
+    /// Allow an address to mint badges for the event (but nothing else) until `expires`,
+    /// or forever if unset, and at most `quota` badges if set. Replaces any previous grant of
+    /// the minter
+    AddMinter {
+        event: String,
+        minter: String,
+        expires: Option<Expiration>,
+        #[serde(default)]
+        quota: Option<u32>,
+    },
+    /// Revoke a minter grant before it expires
+    RemoveMinter {
+        event: String,
+        minter: String,
+    },
+    /// Anyone may delete the expired role grants of an event
+    PruneMinters {
+        event: String,
+    },
+    /// Anyone may delete, across all events, reservations and claim codes that can't be
+    /// claimed anymore (the event is over, frozen or archived) and expired role grants.
+    /// Looks at no more than `limit` entries of each kind, continuing where the last call
+    /// stopped
+    Cleanup {
+        limit: Option<u32>,
+    },
+    /// Give an address a role on the event until `expires`, or forever if unset. Replaces
+    /// any previous grant. Owners may grant any role, admins only Minter and Viewer. With a
+    /// quota, the grantee may mint at most that many badges
+    GrantRole {
+        event: String,
+        grantee: String,
+        role: Role,
+        expires: Option<Expiration>,
+        #[serde(default)]
+        quota: Option<u32>,
+    },
+    /// Revoke a role grant before it expires. Admins may not revoke other admins
+    RevokeRole {
+        event: String,
+        grantee: String,
+    },
+    /// Create a new series, which groups multiple events together
+    CreateSeries {
+        name: String,
+        description: String,
+    },
+    /// Attach an event to a series. Sender must own both the series and the event
+    AddToSeries {
+        series: String,
+        event: String,
+    },
+    /// Mint a badge of the streak event to attendees of the given number of consecutive events
+    /// of the series, or stop with None. Sender must own both the series and the streak event,
+    /// which can't be part of the series
+    SetSeriesStreak {
+        series: String,
+        streak: Option<SeriesStreak>,
+    },
+    /// Let holders of badges of all the required events (anyone's) claim a badge of `event`,
+    /// which the sender must own. Redefining an achievement is left to its owner
+    DefineAchievement {
+        id: String,
+        event: String,
+        required: Vec<String>,
+    },
+    /// Only by the achievement's owner. Badges already claimed are kept
+    RemoveAchievement {
+        id: String,
+    },
+    /// Mint the achievement's badge to the sender, who must hold all its required badges
+    ClaimAchievement {
+        id: String,
+    },
+    /// Correct the was_late flag on an issued badge. Only allowed until the event is frozen
+    UpdateBadge {
+        event: String,
+        attendee: String,
+        was_late: bool,
+    },
+    /// Set or remove (None) the personalized artwork of an issued badge. Only allowed until
+    /// the event is frozen
+    SetBadgeImage {
+        event: String,
+        attendee: String,
+        image: Option<String>,
+    },
+    /// Admin only: allow the organizer to register events in allowlisted mode
+    AddOrganizer {
+        organizer: String,
+    },
+    /// Admin only: replace the name, description and image this deployment is labelled with,
+    /// or remove them
+    SetDeploymentInfo {
+        deployment: Option<DeploymentInfo>,
+    },
+    /// Admin only: replace max_active_events for the organizer (eg. for a conference with
+    /// many tracks). None restores the configured limit
+    SetOrganizerEventLimit {
+        organizer: String,
+        limit: Option<u32>,
+    },
+    /// Admin only
+    RemoveOrganizer {
+        organizer: String,
+    },
+    /// Admin only: set the price of registrations or staff mints in the coin's denom. Payers
+    /// pick any priced denom. A zero amount stops accepting the denom
+    SetFee {
+        kind: FeeKind,
+        price: Coin,
+    },
+    /// Admin only: replace the registration fee discounts organizers earn with completed events
+    /// (finalized with badges, not cancelled). The largest one reached applies
+    SetFeeDiscounts {
+        discounts: Vec<FeeDiscount>,
+    },
+    /// Admin only: add or replace a subscription plan. Current subscribers keep the old terms
+    /// until they renew
+    SetSubscriptionPlan {
+        name: String,
+        plan: SubscriptionPlan,
+    },
+    /// Admin only: no new subscriptions or renewals, current ones run until they expire
+    RemoveSubscriptionPlan {
+        name: String,
+    },
+    /// Pay for one period of the plan. Renewing the current plan extends it, subscribing to
+    /// another one replaces it from now on
+    Subscribe {
+        plan: String,
+    },
+    /// Admin only: in curated mode, allow badges to be minted for a newly registered event
+    ApproveEvent {
+        event: String,
+    },
+    /// Permanently lock the event, no more badges can be minted or updated
+    FreezeEvent {
+        event: String,
+    },
+    /// End a running event now, before its scheduled end_time (eg. the venue closed early).
+    /// Its claim window closes with it
+    CloseEventEarly {
+        event: String,
+    },
+    /// Cancel an event before it is finalized. It is frozen, and its sponsors and raffle
+    /// funder are refunded, the sponsors in batches of MAX_REFUND_BATCH. Registration and
+    /// mint fees paid to the contract are not refunded
+    CancelEvent {
+        event: String,
+    },
+    /// Anyone may refund the next batch of sponsors of a cancelled event
+    ContinueRefunds {
+        event: String,
+        limit: Option<u32>,
+    },
+    /// Require approvals of owners, by weight, to freeze the event or change its owners and
+    /// council. Members that are not owners yet become co-owners. Once set, the council can
+    /// only be changed through a proposal
+    SetEventCouncil {
+        event: String,
+        members: Vec<Member>,
+        threshold: u64,
+    },
+    /// Propose an action to the event council, approved by the sender. It is executed as soon
+    /// as the approvals reach the threshold
+    ProposeEventAction {
+        event: String,
+        action: EventAction,
+    },
+    ApproveEventAction {
+        event: String,
+        proposal_id: u64,
+    },
+    /// Send `amount` of a cw20 token to every new badge holder, or stop if unset. The tokens
+    /// are taken from the sender's allowance to this contract, so mints fail once it runs out
+    SetCw20Reward {
+        event: String,
+        reward: Option<Cw20RewardMsg>,
+    },
+    /// Accept ticket NFTs of a cw721 collection for the event, or stop if unset. Tickets are
+    /// burned if `burn` is set, else they stay locked in this contract
+    SetNftTicketCollection {
+        event: String,
+        collection: Option<String>,
+        #[serde(default)]
+        burn: bool,
+    },
+    /// Sent by a cw721 contract on SendNft. The ticket's previous owner gets the badge of the
+    /// event named in the `NftTicketMsg` payload, if the event accepts this collection
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Accept `amount` of a cw20 token as the ticket of the event, or stop if unset. Deposited
+    /// tickets are burned
+    SetCw20TicketToken {
+        event: String,
+        token: Option<String>,
+        amount: Uint128,
+    },
+    /// Sent by a cw20 contract on Send. Depositing exactly the ticket amount of the event named
+    /// in the `Cw20TicketMsg` payload mints its badge to the sender
+    Receive(Cw20ReceiveMsg),
+    /// Fund a raffle among the event's badge holders with the attached coin, drawn when the
+    /// event is finalized. `fee` is taken from it to pay the Nois proxy, and the rest is split
+    /// equally among `winners` holders. Owners only, before the event is finalized
+    FundRaffle {
+        event: String,
+        winners: u32,
+        #[serde(default)]
+        fee: Uint128,
+    },
+    /// Callback of the Nois proxy with the randomness of a raffle, whose job id is the event
+    NoisReceive {
+        callback: NoisCallback,
+    },
+    /// Anyone may archive an event once its claim window closed: it is frozen, an archive
+    /// record with its counts, holders merkle root and metadata hash is saved, and the minting
+    /// state (minters, claim codes, check-ins, waitlist...) is pruned. Badges are removed, their
+    /// holders proving attendance with `VerifyArchivedAttendee`
+    ArchiveEvent {
+        name: String,
+    },
+    /// Return the rest of the event's reward pool to the sender, after the claim window
+    WithdrawRewards {
+        event: String,
+    },
+    /// Sponsor the event with the attached funds, shown with the name and logo in GetEvent.
+    /// Sponsoring again adds to the funds and replaces the name and logo. Sponsorships are
+    /// closed once the claim window is over
+    SponsorEvent {
+        event: String,
+        name: String,
+        logo: Option<String>,
+    },
+    /// Pay the sponsorships to the event owner, once the claim window closed on a successful
+    /// event: approved, with at least one badge issued
+    ReleaseSponsorships {
+        event: String,
+    },
+    /// Return the sender's sponsorship if the event did not succeed, once its claim window
+    /// closed
+    WithdrawSponsorship {
+        event: String,
+    },
+    /// Add the attached coin to the event's gas budget, and set the fee allowance granted from
+    /// it to each attendee. Owners only
+    FundGasBudget {
+        event: String,
+        allowance: Uint128,
+    },
+    /// Grant attendees with a reservation a fee allowance from the gas budget, so they can
+    /// claim their badge from an empty wallet. It expires with the claim window and only
+    /// pays for contract executions. Attendees with a badge or an allowance are skipped.
+    /// Owners and minters, at most MAX_GAS_GRANTS attendees at once
+    GrantClaimGas {
+        event: String,
+        attendees: Vec<String>,
+    },
+    /// Return what is left of the gas budget to the sender. Owners only. Unspent allowances
+    /// already granted are not returned
+    WithdrawGasBudget {
+        event: String,
+    },
+    /// Return the registration deposit to the sender who paid it, once the event is over
+    RefundDeposit {
+        event: String,
+    },
+    /// Admin only: keep the registration deposit of a spam event
+    ForfeitDeposit {
+        event: String,
+    },
+    /// Admin only: send all funds held by the contract to the recipient (default: contract owner).
+    /// Reward pools and registration deposits are not touched
+    SweepFunds {
+        recipient: Option<String>,
+    },
+    /// Admin only: announce that the event will be handed to new_owner, when its organizer
+    /// lost their key or it was compromised. It can be completed after a 3 day delay, leaving
+    /// time to object. Announcing again restarts the delay
+    ForceTransferEvent {
+        event: String,
+        new_owner: String,
+    },
+    /// Admin only: hand the event to the announced owner once the delay passed. The previous
+    /// owner loses all rights on it
+    CompleteEventRecovery {
+        event: String,
+    },
+    /// Admin only: drop an announced recovery, eg. after an objection
+    CancelEventRecovery {
+        event: String,
+    },
+    /// Reserve a badge for the attendee, who must then claim it themselves
+    ReserveBadge {
+        event: String,
+        attendee: String,
+    },
+    /// Accept a reserved badge, minting it to the sender
+    ClaimReserved {
+        event: String,
+    },
+    /// Accept up to `limit` (default 10, max 30) reserved badges at once, in event name order
+    /// after `start_after`. Those that can't be claimed are skipped. A ClaimAllReservedResponse
+    /// is returned as data
+    ClaimAllReserved {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Upload hex encoded sha256 hashes of single-use claim codes (eg. printed QR tickets)
+    AddClaimCodes {
+        event: String,
+        hashes: Vec<String>,
+    },
+    /// Mint a badge to the sender by revealing an unused claim code of the event. Claims may
+    /// credit a `referrer`, counted by the Referrals query
+    ClaimWithCode {
+        event: String,
+        code: String,
+        referrer: Option<String>,
+    },
+    /// Kiosks registered for the event commit the hex sha256 of the nonce they display,
+    /// replacing the previous one. It can be revealed for `valid_for` seconds
+    CommitKioskNonce {
+        event: String,
+        nonce_hash: String,
+        valid_for: u64,
+    },
+    /// Owners, minters and kiosks add the hex sha256 of a claim secret shown on site, which
+    /// any attendee may reveal during its window (at most MAX_CLAIM_SECRET_WINDOW seconds).
+    /// Secrets whose window ended are pruned, and with `replace` all the others are removed,
+    /// so a leaked secret can be retired at once
+    RotateClaimSecret {
+        event: String,
+        hash: String,
+        window: ClaimWindow,
+        #[serde(default)]
+        replace: bool,
+    },
+    /// Mint a badge to the sender by revealing a claim secret of the event during its window
+    ClaimWithSecret {
+        event: String,
+        secret: String,
+        referrer: Option<String>,
+    },
+    /// As an admin of the event, register a named check-in station. It may mint badges and
+    /// commit kiosk nonces without a role, only in its tier and window if set. Registering it
+    /// again replaces its settings
+    RegisterKiosk {
+        event: String,
+        kiosk: String,
+        name: String,
+        tier: Option<String>,
+        window: Option<ClaimWindow>,
+    },
+    /// Removing a kiosk also revokes the nonce it showed
+    RemoveKiosk {
+        event: String,
+        kiosk: String,
+    },
+    /// Mint a badge to the sender by revealing the nonce currently shown by the kiosk
+    ClaimWithNonce {
+        event: String,
+        kiosk: String,
+        nonce: String,
+        referrer: Option<String>,
+    },
+    /// Owners and minters record the arrival of an attendee at an event with min_duration
+    CheckIn {
+        event: String,
+        attendee: String,
+    },
+    /// Record that the attendee left. The badge is minted if they stayed long enough
+    CheckOut {
+        event: String,
+        attendee: String,
+    },
+    /// Badge holders may rate an event they attended once, with a score from 1 to 5
+    RateEvent {
+        event: String,
+        score: u8,
+        comment: Option<String>,
+    },
+    /// Report an abusive event. Each address may flag an event once
+    FlagEvent {
+        event: String,
+        reason: String,
+    },
+    /// Permanently remove the sender's badge for the event
+    BurnBadge {
+        event: String,
+    },
+    /// Redeem a perk of the sender's badge (eg. "t-shirt"), once per badge and perk.
+    /// The badge must not be expired
+    RedeemBadge {
+        event: String,
+        perk_id: String,
+    },
+    /// Bind a public key to the sender's badge, replacing the previous one. `signature` is
+    /// the key's signature of `helpers::badge_key_challenge` (the sha256 of
+    /// "bind-badge-key:{contract}:{event}:{holder}"), proving the holder controls it
+    BindBadgeKey {
+        event: String,
+        algorithm: KeyAlgorithm,
+        pubkey: Binary,
+        signature: Binary,
+    },
+    /// Remove the key bound to the sender's badge
+    UnbindBadgeKey {
+        event: String,
+    },
+    /// Call the contract with PoapHookMsg::BadgeMinted for every new badge of the event, or
+    /// stop if the policy is unset. Event admins only, at most 5 hooks per event
+    SetMintHook {
+        event: String,
+        contract: String,
+        policy: Option<HookPolicy>,
+    },
+    /// Replace the personal data of the sender's badge (note, memo, attributes, image and
+    /// bound key) with a tombstone. The badge and the event's counts are kept
+    EraseMyData {
+        event: String,
+    },
+    /// Badge holders may set a nickname of up to 32 characters, shown next to their address
+    /// in attendee listings. Unset to remove it
+    SetNickname {
+        nickname: Option<String>,
+    },
+    /// Lock the note, attributes and image of the attendee's badge for good, so consumers can
+    /// rely on them. The holder can freeze their own badge (attendee unset), event admins any
+    /// badge if the event's badge_freeze_policy allows it
+    FreezeBadgeMetadata {
+        event: String,
+        attendee: Option<String>,
+    },
+    /// Give the sender's badge to another address, if the event allows transfers
+    TransferBadge {
+        event: String,
+        recipient: String,
+    },
+    /// Transfer the attendee's badge as an approved spender or operator (eg. a marketplace)
+    TransferBadgeFrom {
+        event: String,
+        attendee: String,
+        recipient: String,
+    },
+    /// Mint a copy of the sender's badge on a cw721 contract that has this contract as its
+    /// minter, with the same token id and metadata. The badge stays here, but can no longer
+    /// be transferred or exported again
+    ExportBadge {
+        event: String,
+        target_cw721: String,
+    },
+    /// Reward the attendees of a frozen event through a cw20-merkle-airdrop contract owned by
+    /// this one (with `native_token` set to the denom). Registers the merkle root over the
+    /// attendance snapshot and forwards the funds, `amount_per_attendee` times the attendees,
+    /// sent with the message. Event admins only, once per airdrop contract
+    CreateAirdrop {
+        event: String,
+        airdrop_contract: String,
+        amount_per_attendee: Coin,
+    },
+    /// As cw721 Approve: allow the spender to transfer the sender's badge for the event, until
+    /// `expires` or forever if unset. Only for events that allow transfers
+    Approve {
+        event: String,
+        spender: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        event: String,
+        spender: String,
+    },
+    /// As cw721 ApproveAll: allow the operator to transfer any of the sender's badges
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    RevokeAll {
+        operator: String,
+    },
+    /// Change or remove the maximum number of badges held at once. Raising it lets
+    /// waitlisted addresses claim
+    SetCapacity {
+        event: String,
+        capacity: Option<u32>,
+    },
+    /// Open (or close, if unset) a window of at most a day, within the mint window, during
+    /// which any address may claim one badge without a code. Capacity and the per-block rate
+    /// limit still apply
+    SetOpenClaim {
+        event: String,
+        window: Option<ClaimWindow>,
+    },
+    /// Make badges claimed by attendees themselves (claim codes, kiosks, open claims,
+    /// reservations, tickets and waitlists) provisional for `period` seconds, at most a week.
+    /// Provisional badges earn no rewards and can't be transferred or exported. Unset to
+    /// accept claims right away
+    SetChallengePeriod {
+        event: String,
+        period: Option<u64>,
+    },
+    /// Remove a provisional badge before its challenge period ends. The attendee may not
+    /// claim a badge for the event again
+    ChallengeClaim {
+        event: String,
+        attendee: String,
+    },
+    /// As an admin of the event, revoke the badges of up to 100 attendees (eg. a mis-scanned
+    /// batch). Attendees that fail are skipped and reported, the others are revoked. A
+    /// RevokeBadgesResponse is returned as data
+    RevokeBadges {
+        event: String,
+        attendees: Vec<String>,
+    },
+    /// Mint a badge to the sender while the event's open claim window runs
+    OpenClaim {
+        event: String,
+        referrer: Option<String>,
+    },
+    /// Issue a ticket for the event to the holder, as an owner or minter (eg. once paid).
+    /// Tickets count against the capacity and can be transferred until checked in
+    IssueTicket {
+        event: String,
+        holder: String,
+    },
+    TransferTicket {
+        event: String,
+        recipient: String,
+    },
+    /// At the venue, convert the holder's ticket into a soulbound badge
+    CheckInTicket {
+        event: String,
+        holder: String,
+    },
+    /// Join the waitlist of a full event, to claim a badge once a slot frees up
+    JoinWaitlist {
+        event: String,
+    },
+    LeaveWaitlist {
+        event: String,
+    },
+    /// Mint a badge to the sender, if they are among the first waitlisted addresses to fit
+    /// in the free slots
+    ClaimFromWaitlist {
+        event: String,
+        referrer: Option<String>,
+    },
+    /// Suspend minting, claims and check-ins for the event (eg. during a fire alarm), or
+    /// resume them. Unlike freezing, this can be undone
+    SetMintingPaused {
+        event: String,
+        paused: bool,
+    },
+    /// Allow or forbid transfers of the event's badges. Badges are soulbound by default
+    SetTransferable {
+        event: String,
+        transferable: bool,
+    },
+    /// Add a dated occurrence (eg. one week of a weekly meetup) within the event's time range
+    AddOccurrence {
+        event: String,
+        start_time: u64,
+        end_time: u64,
+    },
+    /// Change the event's metadata. Locked once a badge was minted, unless the event was
+    /// registered with allow_post_mint_edits
+    UpdateEventMetadata {
+        event: String,
+        update: EventMetadataUpdate,
+    },
+    /// Replace the venue name and coordinates of the event. Event admins only, until frozen
+    SetEventVenue {
+        event: String,
+        venue: Option<String>,
+        location: Option<GeoPoint>,
+    },
+    /// Replace the event's opaque extension payload, at most 1024 bytes. Event admins only,
+    /// until frozen
+    SetEventExtension {
+        event: String,
+        extension: Option<Binary>,
+    },
+    /// Set the name and description of the event in another language, like "ko" or "pt-BR",
+    /// for at most 20 languages. None removes the language
+    SetTranslation {
+        event: String,
+        lang: String,
+        translation: Option<Translation>,
+    },
+    /// Admin only: trust the attendance root of an event on a counterpart deployment on another
+    /// chain, as returned by its AttendanceRoot query (eg. relayed by a bot). None removes it
+    SetRemoteRoot {
+        chain: String,
+        event: String,
+        root: Option<String>,
+    },
+    /// Check a proof from the counterpart's AttendanceProof query that the attendee holds a
+    /// badge of the remote event. The result is cached for the RemoteBadge query
+    VerifyRemoteBadge {
+        chain: String,
+        event: String,
+        attendee: String,
+        proof: Vec<String>,
+    },
+    /// As a minter of the event, mint a badge to an address of another chain, checked against
+    /// its declared format. Such badges are listed apart and can't be transferred
+    MintForeignBadge {
+        event: String,
+        address: String,
+        format: AddressFormat,
+    },
+    /// As a minter of a private event, record a badge for the hex sha256 of a salt kept by the
+    /// organizers followed by the attendee address. The attendee proves it with HasBadge
+    MintPrivateBadge {
+        event: String,
+        attendee_hash: String,
+    },
+    /// Anyone may run the next batch of at most `limit` badges of an unfinished migration
+    ContinueMigration {
+        limit: Option<u32>,
+    },
+    /// Admin only: update the contract configuration
+    UpdateConfig {
+        limits: Option<ValidationLimits>,
+        /// A zero amount removes the deposit requirement
+        registration_deposit: Option<Coin>,
+        require_approval: Option<bool>,
+        /// Number of flags that pause an event for review. 0 disables it
+        flag_threshold: Option<u32>,
+        /// Replaces the hostnames image URLs must be hosted on (eg. an ipfs gateway).
+        /// An empty list allows any host
+        allowed_image_domains: Option<Vec<String>>,
+        /// Contract resolving attendee names to addresses. An empty string removes it
+        #[serde(default)]
+        name_registry: Option<String>,
+        /// Nois proxy drawing raffle winners. An empty string removes it
+        #[serde(default)]
+        nois_proxy: Option<String>,
+        /// Events that haven't ended an organizer may have at once. 0 removes the limit
+        #[serde(default)]
+        max_active_events: Option<u32>,
+        /// Badges a partial MintBadges or ContinueBatch mints per message. 0 restores the
+        /// default of 50
+        #[serde(default)]
+        batch_mint_limit: Option<u32>,
+    },
+    /// Propose, accept or renounce contract ownership (two-step transfer)
+    UpdateOwnership(Action),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintBadgeMsg {
+    pub event: String,
+    pub attendee: String,
+    pub was_late: bool,
+    /// Optional short free-text note, like "won hackathon track 2"
+    pub note: Option<String>,
+    /// For recurring events, the occurrence that was attended. The event badge is minted
+    /// on the first attended occurrence
+    pub occurrence: Option<u32>,
+    /// One of the tiers defined for the event
+    #[serde(default)]
+    pub tier: Option<String>,
+    /// Optional short reason for the mint, like "booth 12 scan", kept as an audit trail
+    #[serde(default)]
+    pub memo: Option<String>,
+    /// Succeed without minting if the attendee already has the badge (or attended the
+    /// occurrence), with an `already_issued` attribute, so retried batches don't abort
+    #[serde(default)]
+    pub idempotent: bool,
+    /// Per-badge data like the ticket id or seat, with unique keys. At most
+    /// MAX_BADGE_ATTRIBUTES entries
+    #[serde(default)]
+    pub attributes: Vec<BadgeAttribute>,
+    /// Personalized artwork of the badge, validated like event images
+    #[serde(default)]
+    pub image: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintFailure {
+    pub event: String,
+    /// ContractError::code
+    pub code: u32,
+    pub error: String,
+}
+
+/// Data of the MintForEvents response
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintForEventsResponse {
+    pub minted: Vec<String>,
+    pub failed: Vec<MintFailure>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BatchMintFailure {
+    pub attendee: String,
+    /// ContractError::code
+    pub code: u32,
+    pub error: String,
+}
+
+/// Data of the MintBadges and ContinueBatch responses
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintBadgesResponse {
+    pub minted: Vec<String>,
+    pub failed: Vec<BatchMintFailure>,
+    /// Attendees left for ContinueBatch
+    pub remaining: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevokeFailure {
+    pub attendee: String,
+    /// ContractError::code
+    pub code: u32,
+    pub error: String,
+}
+
+/// Data of the RevokeBadges response
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevokeBadgesResponse {
+    pub revoked: Vec<String>,
+    pub failed: Vec<RevokeFailure>,
+}
+
+/// Data of the ClaimAllReserved response
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimAllReservedResponse {
+    pub claimed: Vec<String>,
+    pub failed: Vec<MintFailure>,
+    /// Pass as `start_after` to claim the next reservations, None if there are no more
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20RewardMsg {
+    /// Address of the cw20 contract
+    pub token: String,
+    pub amount: Uint128,
+}
+
+/// The subset of cw20::Cw20ExecuteMsg this contract sends
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20ExecuteMsg {
+    TransferFrom {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+    Burn {
+        amount: Uint128,
+    },
+}
+
+/// As cw20::Cw20ReceiveMsg, the hook a cw20 contract calls on Send
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20ReceiveMsg {
+    pub sender: String,
+    pub amount: Uint128,
+    pub msg: Binary,
+}
+
+/// The `msg` of Send when redeeming cw20 tickets with this contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20TicketMsg {
+    pub event: String,
+}
+
+/// The subset of the Nois proxy ExecuteMsg this contract sends
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NoisProxyExecuteMsg {
+    GetNextRandomness { job_id: String },
+}
+
+/// As nois::NoisCallback. The randomness is 32 bytes, hex encoded
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NoisCallback {
+    pub job_id: String,
+    pub published: Timestamp,
+    pub randomness: String,
+}
+
+/// The query this contract sends to the name registry (the ICNS resolver interface)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NameRegistryQueryMsg {
+    Address { name: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NameAddressResponse {
+    pub address: String,
+}
+
+/// The subset of cw721_base::ExecuteMsg this contract sends
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum Cw721ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: Metadata,
+    },
+    Burn {
+        token_id: String,
+    },
+}
+
+/// The subset of cw20_merkle_airdrop::msg::ExecuteMsg this contract sends
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MerkleAirdropExecuteMsg {
+    RegisterMerkleRoot {
+        merkle_root: String,
+        total_amount: Option<Uint128>,
+    },
+}
+
+/// Sent to the mint hooks of an event, wrapped as the hook contract's ExecuteMsg variant
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PoapHookMsg {
+    BadgeMinted { event: String, attendee: String },
+}
+
+/// As cw721::Cw721ReceiveMsg, the hook a cw721 contract calls on SendNft
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+/// The `msg` of SendNft when sending a ticket to this contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftTicketMsg {
+    pub event: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RegisterEventMsg {
+    pub name: String,
+    pub image: String,
+    pub description: String,
+    /// Optional JSON document with additional structured metadata
+    pub metadata: Option<String>,
+    /// Event page, ticketing link or governance proposal. Must be https://
+    pub external_url: Option<String>,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Additional addresses with the same rights as the owner, at most 20
+    #[serde(default)]
+    pub co_owners: Vec<String>,
+    /// If set, no more than this many badges can be minted for the event in one block.
+    /// Must not be 0
+    pub max_mints_per_block: Option<u32>,
+    /// If set, badges for this event expire after this timestamp (eg. yearly memberships)
+    pub badge_valid_until: Option<u64>,
+    /// If set, badges can still be minted after end_time until this timestamp
+    pub claim_until: Option<u64>,
+    /// If set, badges are only minted on CheckOut, to attendees present at least this many
+    /// seconds since CheckIn
+    pub min_duration: Option<u64>,
+    /// Image and description can still be edited after the first badge was minted.
+    /// Off by default, so collectors get the artwork they claimed
+    #[serde(default)]
+    pub allow_post_mint_edits: bool,
+    /// If set, at most this many badges can be held at once. Once full, addresses may join
+    /// the waitlist
+    #[serde(default)]
+    pub capacity: Option<u32>,
+    /// Badge tiers, like "speaker" or "attendee". A tier's image replaces the event image on
+    /// its badges
+    #[serde(default)]
+    pub tiers: Vec<Tier>,
+    /// Animated artwork or video, shown by NFT galleries instead of the image. Must be https://
+    pub animation_url: Option<String>,
+    /// Six hex digits without the "#", like "1a2b3c"
+    pub background_color: Option<String>,
+    /// Hex-encoded sha256 of the image (or metadata file), so clients can check the hosted
+    /// artwork was not swapped after registration
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// Where start and end times should be shown: a UTC offset like "+09:00", or an IANA
+    /// timezone like "Asia/Seoul"
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// If set, every new badge holder is sent this amount from a reward pool, funded with the
+    /// coins sent along with this message
+    pub reward: Option<Coin>,
+    /// Attendees are only recorded as hashes (see MintPrivateBadge), so the attendee list
+    /// can't be read from the chain. Fixed at registration
+    #[serde(default)]
+    pub private: bool,
+    /// If set, the event is hidden from ListAllEvents and ListEventSummaries and badges can't
+    /// be minted until this timestamp, so drops can be prepared without leaking them
+    #[serde(default)]
+    pub publish_at: Option<u64>,
+    /// Name of the place the event is held at
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// Coordinates of the venue, so the event shows up in EventsInArea
+    #[serde(default)]
+    pub location: Option<GeoPoint>,
+    /// Whether event admins may freeze badge metadata, besides holders. Fixed at registration
+    #[serde(default)]
+    pub badge_freeze_policy: BadgeFreezePolicy,
+    /// Opaque data of up to 1024 bytes for other applications (eg. a ticketing id or CRM
+    /// reference), never interpreted by this contract
+    #[serde(default)]
+    pub extension: Option<Binary>,
+}
+
+/// Fields left out are unchanged
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct EventMetadataUpdate {
+    pub image: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<String>,
+    pub external_url: Option<String>,
+    pub animation_url: Option<String>,
+    pub background_color: Option<String>,
+    /// Should be updated along with the image
+    pub sha256: Option<String>,
+    pub timezone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    GetEvent {
+        name: String,
+    },
+    /// The owner's event that ends first among those not over yet, so a running event is
+    /// usually returned before upcoming ones
+    NextEventOf {
+        owner: String,
+    },
+    /// Totals over all the events registered by owner, for an organizer dashboard
+    OrganizerStats {
+        owner: String,
+    },
+    /// Returns the event with name and description in the requested language if available,
+    /// falling back to the base language ("pt" for "pt-BR") and then the original text
+    GetLocalizedEvent {
+        name: String,
+        lang: String,
+    },
+    /// Events ordered by name, or by `sort`. All list queries return at most `limit` items
+    /// (default 10, max 30) after `start_after`, the last item of the previous page.
+    /// With `reverse`, pages go in descending order (eg. most badges first)
+    ListAllEvents {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        #[serde(default)]
+        reverse: bool,
+        #[serde(default)]
+        sort: EventSort,
+    },
+    /// The published events located in the box, bounds included, in millionths of a degree.
+    /// A box with min_lon above max_lon crosses the antimeridian. Ordered by latitude band
+    EventsInArea {
+        min_lat: i32,
+        max_lat: i32,
+        min_lon: i32,
+        max_lon: i32,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// As ListAllEvents, but only the fields needed by list views, without the metadata
+    ListEventSummaries {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        #[serde(default)]
+        reverse: bool,
+        #[serde(default)]
+        sort: EventSort,
+        /// Only events with this status
+        #[serde(default)]
+        status: Option<EventStatus>,
+    },
+    /// Where the event is in its lifecycle
+    EventStatus {
+        event: String,
+    },
+    /// Events waiting for the admin's approval in curated mode, ordered by name
+    ListPendingEvents {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Badges issued for the event, ordered by attendee address
+    ListAttendees {
+        name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        #[serde(default)]
+        reverse: bool,
+    },
+    /// Up to `count` holders of the event's badge, drawn with the seed. The same seed and
+    /// holders always give the same sample, so off-chain draws can be reproduced
+    SampleAttendees {
+        event: String,
+        seed: String,
+        count: u32,
+    },
+    /// Badges of the attendee, ordered by event name
+    ListMyBadges {
+        attendee: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        #[serde(default)]
+        reverse: bool,
+    },
+    ListOccurrences {
+        event: String,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    /// As cw721 Minter, for the event: its original owner, and every address that may
+    /// currently mint badges
+    Minter {
+        event: String,
+    },
+    /// Grants of the roles that may mint (Minter and Admin), ordered by address, including
+    /// expired ones not pruned yet
+    ListMinters {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Badges of the event claimed with each referrer and still held, ordered by referrer
+    Referrals {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// All role grants of the event, ordered by address, including expired ones not pruned yet
+    EventRoles {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Check-in stations registered for the event, ordered by address
+    ListKiosks {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// What the address may currently do on the event, eg. to adapt a check-in app's UI
+    Permissions {
+        event: String,
+        address: String,
+    },
+    /// The last 20 metadata edits of the event, oldest first, with the previous values
+    EventHistory {
+        event: String,
+    },
+    /// Privileged actions (edits, revocations, ownership and config changes), oldest first.
+    /// Only the last 1000 are kept. `start_after` is the id of the last entry already seen
+    AuditLog {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Events registered and badges minted after the given block height, oldest first.
+    /// `start_after` is the id of the last change already seen
+    ChangesSince {
+        height: u64,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Raw storage entries in key order, for state dumps and off-chain mirrors. A page stops
+    /// at `limit` entries or once it holds MAX_RAW_STATE_BYTES of keys and values
+    RawState {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
+    /// The step an unfinished migration continues from, if any
+    MigrationStatus {},
+    /// The latest badges minted across all events, newest first, for live dashboards. Only
+    /// mints after the `since` timestamp are returned, and at most the last 100 are kept
+    RecentBadges {
+        since: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// As cw721 Approval: the spender's approval for the attendee's badge, if any
+    Approval {
+        event: String,
+        attendee: String,
+        spender: String,
+        #[serde(default)]
+        include_expired: bool,
+    },
+    /// All approvals for the attendee's badge
+    Approvals {
+        event: String,
+        attendee: String,
+        #[serde(default)]
+        include_expired: bool,
+    },
+    /// As cw721 Operator: the operator's approval for all the owner's badges, if any
+    Operator {
+        owner: String,
+        operator: String,
+        #[serde(default)]
+        include_expired: bool,
+    },
+    AllOperators {
+        owner: String,
+        #[serde(default)]
+        include_expired: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// As cw721 AllTokens: ids of every badge, ordered by event then attendee. Token ids are
+    /// "{event}/{attendee}", see helpers::token_id
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// As cw721 NumTokens: number of badges currently held, across all events
+    NumTokens {},
+    /// Metadata of a badge in the cw721 NftInfo format, with the usual marketplace fields
+    NftInfo {
+        event: String,
+        attendee: String,
+    },
+    /// Flags raised against the event, ordered by address
+    EventFlags {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Events flagged since their last review, ordered by name
+    FlaggedEvents {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Which occurrences of a recurring event the attendee was at
+    AttendedOccurrences {
+        event: String,
+        attendee: String,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    /// The contracts called for every new badge of the event
+    MintHooks {
+        event: String,
+    },
+    /// Badges minted in the event per hour, for the hours with any. Pages through up to a week
+    /// of hours at a time, `start_after` being the start of the last hour returned
+    MintHistogram {
+        event: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// When the attendee checked in, if they haven't checked out yet
+    CheckIn {
+        event: String,
+        attendee: String,
+    },
+    /// Per-badge rewards of the event and what is left in the native pool
+    RewardPool {
+        event: String,
+    },
+    /// The cw721 collection whose tickets are converted into badges of the event, if any
+    NftTicketCollection {
+        event: String,
+    },
+    /// The cw20 token and amount redeemed for badges of the event, if any
+    Cw20TicketToken {
+        event: String,
+    },
+    /// The event's raffle and, once drawn, its winners
+    Raffle {
+        event: String,
+    },
+    /// The event's gas budget for claim fees, if any
+    GasBudget {
+        event: String,
+    },
+    /// Registration deposit held for the event, if any
+    Deposit {
+        event: String,
+    },
+    /// Aggregated feedback from badge holders
+    EventRating {
+        event: String,
+    },
+    /// Pending, unclaimed reservations for the attendee
+    ListReservations {
+        attendee: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Events the attendee has a reservation for that can still be claimed, ordered by name
+    AllowlistedEvents {
+        attendee: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Whether a claim code hash was uploaded for the event, and who used it
+    ClaimCode {
+        event: String,
+        hash: String,
+    },
+    /// The event's rotating claim secret hashes and their windows, including those not
+    /// active yet
+    ClaimSecrets {
+        event: String,
+    },
+    /// Who issued the badge, when and through which flow
+    BadgeProvenance {
+        event: String,
+        attendee: String,
+    },
+    /// Number of distinct addresses holding at least one badge
+    UniqueHolders {},
+    /// How many badges the attendee holds, and how many of those were on time
+    BadgeCount {
+        attendee: String,
+    },
+    /// The key bound to the attendee's badge, if any
+    BadgeKey {
+        event: String,
+        attendee: String,
+    },
+    /// When the perk of the attendee's badge was redeemed, if it was
+    Redemption {
+        event: String,
+        attendee: String,
+        perk_id: String,
+    },
+    /// Does the attendee have a badge for the event. With only_valid, expired badges don't count.
+    /// For private events, the salt the badge was minted with must be given as well
+    HasBadge {
+        event: String,
+        attendee: String,
+        #[serde(default)]
+        only_valid: bool,
+        #[serde(default)]
+        salt: Option<String>,
+    },
+    /// Looks up (event, attendee) pairs in one call (at most MAX_LIMIT), in the same order
+    GetBadges {
+        keys: Vec<(String, String)>,
+    },
+    /// Which of the addresses (at most MAX_CHECK_MEMBERS) hold a badge for the event, in the
+    /// same order. With only_valid, expired badges don't count. Always false for private events
+    CheckMembers {
+        event: String,
+        addresses: Vec<String>,
+        #[serde(default)]
+        only_valid: bool,
+    },
+    /// Tickets held by the address that were not checked in yet
+    ListTickets {
+        holder: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The attendee's place in the event's waitlist
+    WaitlistPosition {
+        event: String,
+        attendee: String,
+    },
+    /// The event's open claim window, if any
+    OpenClaim {
+        event: String,
+    },
+    ArchivedEvent {
+        name: String,
+    },
+    /// The recovery announced for the event by the admin, if any
+    EventRecovery {
+        event: String,
+    },
+    ListArchivedEvents {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Checks a merkle proof (hex encoded siblings, see helpers::merkle_proof) that the
+    /// attendee held a badge when the event was archived
+    VerifyArchivedAttendee {
+        event: String,
+        attendee: String,
+        proof: Vec<String>,
+    },
+    /// The badge classes of the event, with the badges held across the event and its classes
+    BadgeClasses {
+        event: String,
+    },
+    /// Which badges of the event and its classes the attendee holds
+    EventAttendance {
+        event: String,
+        attendee: String,
+    },
+    /// Merkle root over the holders of the event, computed when it was frozen
+    AttendanceRoot {
+        event: String,
+    },
+    /// Proof that the attendee is in the attendance root of the event
+    AttendanceProof {
+        event: String,
+        attendee: String,
+    },
+    /// The amount and proof the attendee claims an airdrop created for the event with
+    AirdropProof {
+        event: String,
+        airdrop_contract: String,
+        attendee: String,
+    },
+    /// Whether the remote address was verified to hold a badge of the event on the chain
+    RemoteBadge {
+        chain: String,
+        event: String,
+        attendee: String,
+    },
+    /// Badges of the event minted to foreign addresses, ordered by address
+    ListForeignBadges {
+        event: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Events of which the foreign address holds a badge, ordered by name
+    ForeignAddressBadges {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    EventCouncil {
+        event: String,
+    },
+    /// Proposals of the event council waiting for approvals
+    ListEventProposals {
+        event: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Addresses allowed to register events in allowlisted mode, ordered by address
+    ListOrganizers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    SubscriptionPlans {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The organizer's event templates, by name
+    EventTemplates {
+        organizer: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The organizer's current subscription and what is left of its quota
+    Subscription {
+        organizer: String,
+    },
+    /// Whether the attendee meets every one of the conditions, eg. for token gating. At most
+    /// MAX_LIMIT conditions and MAX_LIMIT events in total
+    CheckGate {
+        attendee: String,
+        conditions: Vec<GateCondition>,
+        #[serde(default)]
+        only_valid: bool,
+    },
+    /// Whether the attendee has a badge for every one of the events (at most MAX_LIMIT)
+    AttendedAll {
+        events: Vec<String>,
+        attendee: String,
+        #[serde(default)]
+        only_valid: bool,
+    },
+    /// Whether the attendee has a badge for at least one of the events (at most MAX_LIMIT)
+    AttendedAny {
+        events: Vec<String>,
+        attendee: String,
+        #[serde(default)]
+        only_valid: bool,
+    },
+    Config {},
+    /// Returns the accepted registration and mint fees. With an organizer, the registration fee
+    /// is the one they pay after their discount
+    Fees {
+        organizer: Option<String>,
+    },
+    /// Returns the contract owner and any pending ownership transfer
+    Ownership {},
+    /// Returns the cw2 contract name and version along with the admin, so integrators can
+    /// detect which feature set a deployed instance supports, and the deployment's label
+    ContractInfo {},
+    GetSeries {
+        name: String,
+    },
+    Achievement {
+        id: String,
+    },
+    /// The attendee's longest run of consecutive events of the series, in start time order
+    SeriesStreak {
+        series: String,
+        attendee: String,
+    },
+    /// How many of the events in the series the attendee has a badge for
+    SeriesProgress {
+        series: String,
+        attendee: String,
+        /// If true, expired badges are not counted
+        #[serde(default)]
+        only_valid: bool,
+    },
+    /// Whether the attendee has badges for at least min_count events of the series
+    CompletedSeries {
+        series: String,
+        attendee: String,
+        min_count: u32,
+        /// If true, expired badges are not counted
+        #[serde(default)]
+        only_valid: bool,
+    },
+}
+
+impl QueryMsg {
+    /// The schema of the response to each query, keyed by its name in the message. Stands in
+    /// for `#[derive(QueryResponses)]`, which needs cosmwasm-schema 1.1
+    pub fn response_schemas() -> BTreeMap<String, RootSchema> {
+        vec![
+            ("get_event", schema_for!(GetEventResponse)),
+            ("next_event_of", schema_for!(NextEventResponse)),
+            ("organizer_stats", schema_for!(OrganizerStatsResponse)),
+            ("get_localized_event", schema_for!(LocalizedEventResponse)),
+            ("list_all_events", schema_for!(ListAllEventsResponse)),
+            ("events_in_area", schema_for!(ListAllEventsResponse)),
+            (
+                "list_event_summaries",
+                schema_for!(ListEventSummariesResponse),
+            ),
+            ("event_status", schema_for!(EventStatusResponse)),
+            ("list_pending_events", schema_for!(ListAllEventsResponse)),
+            ("list_attendees", schema_for!(ListAttendeesResponse)),
+            ("sample_attendees", schema_for!(SampleAttendeesResponse)),
+            ("list_my_badges", schema_for!(ListMyBadgesResponse)),
+            ("list_occurrences", schema_for!(ListOccurrencesResponse)),
+            ("minter", schema_for!(MinterResponse)),
+            ("list_minters", schema_for!(ListMintersResponse)),
+            ("event_roles", schema_for!(EventRolesResponse)),
+            ("referrals", schema_for!(ReferralsResponse)),
+            ("list_kiosks", schema_for!(ListKiosksResponse)),
+            ("permissions", schema_for!(PermissionsResponse)),
+            ("audit_log", schema_for!(AuditLogResponse)),
+            ("event_history", schema_for!(EventHistoryResponse)),
+            ("event_flags", schema_for!(EventFlagsResponse)),
+            ("changes_since", schema_for!(ChangesSinceResponse)),
+            ("raw_state", schema_for!(RawStateResponse)),
+            ("migration_status", schema_for!(MigrationStatusResponse)),
+            ("recent_badges", schema_for!(RecentBadgesResponse)),
+            ("flagged_events", schema_for!(FlaggedEventsResponse)),
+            ("mint_hooks", schema_for!(MintHooksResponse)),
+            ("mint_histogram", schema_for!(MintHistogramResponse)),
+            (
+                "attended_occurrences",
+                schema_for!(AttendedOccurrencesResponse),
+            ),
+            ("list_reservations", schema_for!(ListReservationsResponse)),
+            ("allowlisted_events", schema_for!(AllowlistedEventsResponse)),
+            ("check_in", schema_for!(CheckInResponse)),
+            ("reward_pool", schema_for!(RewardPoolResponse)),
+            (
+                "nft_ticket_collection",
+                schema_for!(NftTicketCollectionResponse),
+            ),
+            ("cw20_ticket_token", schema_for!(Cw20TicketTokenResponse)),
+            ("raffle", schema_for!(RaffleResponse)),
+            ("gas_budget", schema_for!(GasBudgetResponse)),
+            ("redemption", schema_for!(RedemptionResponse)),
+            ("badge_key", schema_for!(BadgeKeyResponse)),
+            ("deposit", schema_for!(DepositResponse)),
+            ("event_rating", schema_for!(EventRatingResponse)),
+            ("claim_code", schema_for!(ClaimCodeResponse)),
+            ("claim_secrets", schema_for!(ClaimSecretsResponse)),
+            ("approval", schema_for!(ApprovalResponse)),
+            ("approvals", schema_for!(ApprovalsResponse)),
+            ("operator", schema_for!(OperatorResponse)),
+            ("all_operators", schema_for!(OperatorsResponse)),
+            ("all_tokens", schema_for!(TokensResponse)),
+            ("num_tokens", schema_for!(NumTokensResponse)),
+            ("nft_info", schema_for!(NftInfoResponse)),
+            ("badge_provenance", schema_for!(BadgeProvenanceResponse)),
+            ("unique_holders", schema_for!(UniqueHoldersResponse)),
+            ("badge_count", schema_for!(BadgeCountResponse)),
+            ("has_badge", schema_for!(HasBadgeResponse)),
+            ("get_badges", schema_for!(GetBadgesResponse)),
+            ("check_members", schema_for!(CheckMembersResponse)),
+            ("list_tickets", schema_for!(ListTicketsResponse)),
+            ("waitlist_position", schema_for!(WaitlistPositionResponse)),
+            ("open_claim", schema_for!(OpenClaimResponse)),
+            ("archived_event", schema_for!(ArchivedEventResponse)),
+            ("event_recovery", schema_for!(EventRecoveryResponse)),
+            (
+                "list_archived_events",
+                schema_for!(ListArchivedEventsResponse),
+            ),
+            (
+                "verify_archived_attendee",
+                schema_for!(VerifyArchivedAttendeeResponse),
+            ),
+            ("badge_classes", schema_for!(BadgeClassesResponse)),
+            ("event_attendance", schema_for!(EventAttendanceResponse)),
+            ("attendance_root", schema_for!(AttendanceRootResponse)),
+            ("attendance_proof", schema_for!(AttendanceProofResponse)),
+            ("airdrop_proof", schema_for!(AirdropProofResponse)),
+            ("remote_badge", schema_for!(RemoteBadgeResponse)),
+            (
+                "list_foreign_badges",
+                schema_for!(ListForeignBadgesResponse),
+            ),
+            (
+                "foreign_address_badges",
+                schema_for!(ForeignAddressBadgesResponse),
+            ),
+            ("event_council", schema_for!(EventCouncilResponse)),
+            (
+                "list_event_proposals",
+                schema_for!(ListEventProposalsResponse),
+            ),
+            ("list_organizers", schema_for!(ListOrganizersResponse)),
+            ("subscription_plans", schema_for!(SubscriptionPlansResponse)),
+            ("event_templates", schema_for!(EventTemplatesResponse)),
+            ("subscription", schema_for!(SubscriptionResponse)),
+            ("check_gate", schema_for!(CheckGateResponse)),
+            ("attended_all", schema_for!(AttendedResponse)),
+            ("attended_any", schema_for!(AttendedResponse)),
+            ("config", schema_for!(ConfigResponse)),
+            ("fees", schema_for!(FeesResponse)),
+            ("ownership", schema_for!(Ownership<String>)),
+            ("contract_info", schema_for!(ContractInfoResponse)),
+            ("get_series", schema_for!(GetSeriesResponse)),
+            ("series_progress", schema_for!(SeriesProgressResponse)),
+            ("series_streak", schema_for!(SeriesStreakResponse)),
+            ("achievement", schema_for!(AchievementResponse)),
+            ("completed_series", schema_for!(CompletedSeriesResponse)),
+        ]
+        .into_iter()
+        .map(|(query, schema)| (query.to_string(), schema))
+        .collect()
+    }
+}
+
+// We define a custom struct for each query response
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeesResponse {
+    /// Price of an event registration, one per accepted denom
+    pub registration: Vec<Coin>,
+    /// Price of a badge minted by staff, one per accepted denom
+    pub mint: Vec<Coin>,
+    /// Registration fee discounts by completed events
+    pub discounts: Vec<FeeDiscount>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub limits: ValidationLimits,
+    pub allow_insecure_urls: bool,
+    pub registration_deposit: Option<Coin>,
+    pub require_approval: bool,
+    pub registry_mode: RegistryMode,
+    pub scoped_event_names: bool,
+    pub flag_threshold: Option<u32>,
+    pub allowed_image_domains: Vec<String>,
+    pub name_registry: Option<String>,
+    pub nois_proxy: Option<String>,
+    pub max_active_events: Option<u32>,
+    pub batch_mint_limit: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventCouncilResponse {
+    pub council: Option<EventCouncil>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventProposalInfo {
+    pub id: u64,
+    pub action: EventAction,
+    pub approvals: Vec<String>,
+    /// Current weight of the approvals
+    pub weight: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListEventProposalsResponse {
+    pub proposals: Vec<EventProposalInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListOrganizersResponse {
+    pub organizers: Vec<String>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriptionPlanInfo {
+    pub name: String,
+    pub plan: SubscriptionPlan,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriptionPlansResponse {
+    pub plans: Vec<SubscriptionPlanInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventTemplateInfo {
+    pub name: String,
+    pub template: EventTemplate,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventTemplatesResponse {
+    pub templates: Vec<EventTemplateInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriptionResponse {
+    /// None if the organizer never subscribed
+    pub plan: Option<SubscriptionPlanInfo>,
+    pub expires: Option<u64>,
+    pub active: bool,
+    /// Events that can still be registered in the current period
+    pub events_remaining: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DepositResponse {
+    pub depositor: Option<String>,
+    pub amount: Option<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ContractInfoResponse {
+    /// cw2 contract name, like "crates.io:dsrv-poap"
+    pub contract: String,
+    /// cw2 contract version
+    pub version: String,
+    /// Contract owner, None if ownership was renounced
+    pub admin: Option<String>,
+    /// cw721 collection name
+    pub name: String,
+    /// cw721 collection symbol
+    pub symbol: String,
+    /// How this deployment is labelled, if the admin set it
+    pub deployment: Option<DeploymentInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetEventResponse {
+    pub owner: String,
+    pub co_owners: Vec<String>,
+    pub name: String,
+    pub image: String,
+    pub description: String,
+    pub metadata: Option<String>,
+    pub external_url: Option<String>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub frozen: bool,
+    pub max_mints_per_block: Option<u32>,
+    pub badge_valid_until: Option<u64>,
+    pub claim_until: Option<u64>,
+    pub transferable: bool,
+    pub min_duration: Option<u64>,
+    pub pending: bool,
+    pub allow_post_mint_edits: bool,
+    pub capacity: Option<u32>,
+    pub challenge_period: Option<u64>,
+    /// When the event was closed before end_time
+    pub closed_at: Option<u64>,
+    pub private: bool,
+    pub cancelled: bool,
+    pub publish_at: Option<u64>,
+    /// Minting is temporarily suspended
+    pub paused: bool,
+    pub tiers: Vec<Tier>,
+    pub animation_url: Option<String>,
+    pub background_color: Option<String>,
+    pub sha256: Option<String>,
+    /// For display only, times are always UTC unix timestamps
+    pub timezone: Option<String>,
+    pub venue: Option<String>,
+    pub location: Option<GeoPoint>,
+    /// The event this is a badge class of
+    pub parent: Option<String>,
+    pub badge_freeze_policy: BadgeFreezePolicy,
+    /// Opaque payload, returned as it was set
+    pub extension: Option<Binary>,
+    /// Badges currently held, kept up to date on mint and burn
+    pub badge_count: u64,
+    pub sponsors: Vec<SponsorInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SponsorInfo {
+    pub sponsor: String,
+    pub name: String,
+    pub logo: Option<String>,
+    pub funds: Vec<Coin>,
+    /// The funds were paid to the event owner
+    pub released: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OrganizerStatsResponse {
+    pub events: u32,
+    /// Badges ever minted into the owner's events, burned ones included
+    pub badges_issued: u64,
+    /// badges_issued per event, zero without events
+    pub average_attendance: Decimal,
+    /// Names of the events that haven't ended yet, soonest ending first. At most 30
+    pub upcoming: Vec<String>,
+    /// Events that haven't ended the owner may have at once, if limited
+    pub max_active_events: Option<u32>,
+    /// Events finalized with badges, cancelled ones excluded. They earn fee discounts
+    pub completed_events: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NextEventResponse {
+    /// None if all the owner's events are over
+    pub event: Option<GetEventResponse>,
+}
+
+impl GetEventResponse {
+    pub fn new(
+        name: String,
+        evt: EventData,
+        meta: EventMetadata,
+        badge_count: u64,
+        sponsors: Vec<SponsorInfo>,
+    ) -> Self {
+        GetEventResponse {
+            owner: evt.owner.to_string(),
+            co_owners: evt.co_owners.into_iter().map(|a| a.into()).collect(),
+            name,
+            image: meta.image,
+            description: meta.description,
+            metadata: meta.metadata,
+            external_url: meta.external_url,
+            start_time: evt.start_time,
+            end_time: evt.end_time,
+            frozen: evt.frozen,
+            max_mints_per_block: evt.max_mints_per_block,
+            badge_valid_until: evt.badge_valid_until,
+            claim_until: evt.claim_until,
+            transferable: evt.transferable,
+            min_duration: evt.min_duration,
+            pending: evt.pending,
+            allow_post_mint_edits: evt.allow_post_mint_edits,
+            capacity: evt.capacity,
+            challenge_period: evt.challenge_period,
+            closed_at: evt.closed_at,
+            private: evt.private,
+            cancelled: evt.cancelled,
+            publish_at: evt.publish_at,
+            paused: evt.paused,
+            venue: evt.venue,
+            location: evt.location,
+            parent: evt.parent,
+            badge_freeze_policy: evt.badge_freeze_policy,
+            extension: evt.extension,
+            tiers: meta.tiers,
+            animation_url: meta.animation_url,
+            background_color: meta.background_color,
+            sha256: meta.sha256,
+            timezone: meta.timezone,
+            badge_count,
+            sponsors,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LocalizedEventResponse {
+    /// The language of the translation used, None if no translation matched
+    pub lang: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub event: GetEventResponse,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListAllEventsResponse {
+    pub events: Vec<GetEventResponse>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventSummary {
+    pub name: String,
+    pub owner: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Badges currently held
+    pub badges: u64,
+    pub status: EventStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventStatusResponse {
+    pub status: EventStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListEventSummariesResponse {
+    pub events: Vec<EventSummary>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListAttendeesResponse {
+    pub attendees: Vec<Attendee>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+    /// Badges of the event currently held
+    pub total: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SampleAttendeesResponse {
+    pub attendees: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Attendee {
+    pub attendee: String,
+    /// Display nickname set by the holder
+    pub nickname: Option<String>,
+    pub was_late: bool,
+    pub note: Option<String>,
+    pub expired: bool,
+    /// Original recipient, if the badge was transferred
+    pub minted_to: Option<String>,
+    /// Seconds between check-in and check-out, for events that require it
+    pub duration: Option<u64>,
+    pub tier: Option<String>,
+    pub attributes: Vec<BadgeAttribute>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListMyBadgesResponse {
+    pub badges: Vec<Badge>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+    /// Badges the attendee currently holds
+    pub total: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Badge {
+    pub event: String,
+    pub was_late: bool,
+    pub note: Option<String>,
+    pub expired: bool,
+    /// Original recipient, if the badge was transferred
+    pub minted_to: Option<String>,
+    /// Seconds between check-in and check-out, for events that require it
+    pub duration: Option<u64>,
+    pub tier: Option<String>,
+    /// Artwork of the badge itself, of its tier, or the event image
+    pub image: String,
+    /// Order in which the badge was minted for the event
+    pub serial: Option<u32>,
+    /// Data the minter attached to the badge
+    pub attributes: Vec<BadgeAttribute>,
+    /// Key the holder bound to the badge
+    pub bound_key: Option<BoundKey>,
+    /// The holder erased the badge's personal data
+    pub erased: bool,
+    /// The note, attributes and image can no longer change
+    pub metadata_frozen: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeLookup {
+    pub event: String,
+    pub attendee: String,
+    /// None if the attendee has no badge for the event
+    pub badge: Option<Badge>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetBadgesResponse {
+    pub badges: Vec<BadgeLookup>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CheckMembersResponse {
+    /// One per queried address
+    pub members: Vec<bool>,
+}
+
+/// cw721's MinterResponse, with all the addresses allowed to mint
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MinterResponse {
+    pub minter: String,
+    /// Owners and co-owners, then the first MAX_LIMIT unexpired minter grants. Use ListMinters
+    /// to page through all grants
+    pub minters: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MinterInfo {
+    pub minter: String,
+    pub expires: Expiration,
+    pub expired: bool,
+    /// Badges the minter may still mint, if limited
+    pub quota: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListMintersResponse {
+    pub minters: Vec<MinterInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralsResponse {
+    pub referrals: Vec<ReferralCount>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralCount {
+    pub referrer: String,
+    pub badges: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoleInfo {
+    pub address: String,
+    pub role: Role,
+    pub expires: Expiration,
+    pub expired: bool,
+    /// Badges the grantee may still mint, if limited
+    pub quota: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRolesResponse {
+    pub roles: Vec<RoleInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct KioskInfo {
+    pub address: String,
+    pub name: String,
+    pub tier: Option<String>,
+    pub window: Option<ClaimWindow>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListKiosksResponse {
+    pub kiosks: Vec<KioskInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+/// Capabilities from ownership or an unexpired role. A frozen event allows no minting,
+/// check-ins or edits. Time windows are not taken into account
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PermissionsResponse {
+    /// Owners and co-owners (including council members) may do everything
+    pub is_owner: bool,
+    /// The address's role grant, expired or not
+    pub role: Option<Role>,
+    pub can_mint: bool,
+    pub can_check_in: bool,
+    /// May edit the event's metadata and settings
+    pub can_edit: bool,
+    /// May revoke role grants, at least those of minters and viewers
+    pub can_revoke: bool,
+}
+
+/// As cw721::Approval
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Approval {
+    pub spender: String,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovalResponse {
+    pub approval: Approval,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovalsResponse {
+    pub approvals: Vec<Approval>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OperatorResponse {
+    pub approval: Approval,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum ExecuteMsg {
-    RegisterEvent {
-        name: String,
-        image: String,
-        description: String,
-        start_time: u64,
-        end_time: u64,
-    },
-    MintBadge {
-        event: String,
-        attendee: String,
-        was_late: bool,
-    },
+pub struct OperatorsResponse {
+    pub operators: Vec<Approval>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum QueryMsg {
-    GetEvent { name: String },
-    // FIXME: add pagination
-    ListAllEvents {},
-    // FIXME: add pagination
-    ListAttendees { name: String },
-    ListMyBadges { attendee: String },
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
 }
 
-// We define a custom struct for each query response
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct GetEventResponse {
-    pub owner: String,
+pub struct NumTokensResponse {
+    pub count: u64,
+}
+
+/// Same shape as cw721's NftInfoResponse
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftInfoResponse {
+    pub token_uri: Option<String>,
+    pub extension: Metadata,
+}
+
+/// The OpenSea metadata standard, as cw721_metadata_onchain::Metadata
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Metadata {
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub external_url: Option<String>,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    /// For badges: the event, tier, serial number and lateness
+    pub attributes: Option<Vec<Trait>>,
+    pub background_color: Option<String>,
+    pub animation_url: Option<String>,
+    pub youtube_url: Option<String>,
+}
+
+/// As cw721_metadata_onchain::Trait
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Trait {
+    pub display_type: Option<String>,
+    pub trait_type: String,
+    pub value: String,
+}
+
+impl Trait {
+    pub fn new(trait_type: &str, value: impl Into<String>) -> Self {
+        Trait {
+            display_type: None,
+            trait_type: trait_type.to_string(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChangeInfo {
+    pub id: u64,
+    pub height: u64,
+    pub change: Change,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChangesSinceResponse {
+    pub changes: Vec<ChangeInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RawEntry {
+    pub key: Binary,
+    pub value: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RawStateResponse {
+    pub entries: Vec<RawEntry>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<Binary>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrationStatusResponse {
+    /// None once the migration is complete
+    pub step: Option<MigrationStep>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevisionInfo {
+    /// Number of the edit, counting from 0 for the first one
+    pub revision: u32,
+    pub editor: String,
+    pub height: u64,
+    pub time: u64,
+    pub changes: Vec<MetadataChange>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventHistoryResponse {
+    pub event: String,
+    pub revisions: Vec<RevisionInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuditInfo {
+    pub id: u64,
+    pub action: String,
+    pub sender: String,
+    pub height: u64,
+    pub time: u64,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuditLogResponse {
+    pub entries: Vec<AuditInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RecentBadgesResponse {
+    pub badges: Vec<RecentMint>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FlagInfo {
+    pub flagger: String,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventFlagsResponse {
+    pub flags: Vec<FlagInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FlaggedEvent {
     pub name: String,
-    pub image: String,
-    pub description: String,
+    /// Flags since the last review
+    pub flags: u32,
+    /// Paused until the admin approves it again
+    pub pending: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FlaggedEventsResponse {
+    pub events: Vec<FlaggedEvent>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Occurrence {
+    pub id: u32,
     pub start_time: u64,
     pub end_time: u64,
+    pub attendees: u32,
 }
 
-impl From<EventData> for GetEventResponse {
-    fn from(evt: EventData) -> Self {
-        GetEventResponse {
-            owner: evt.owner.to_string(),
-            name: evt.name,
-            image: evt.image,
-            description: evt.description,
-            start_time: evt.start_time,
-            end_time: evt.end_time,
-        }
-    }
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListOccurrencesResponse {
+    pub occurrences: Vec<Occurrence>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ListAllEventsResponse {
-    pub events: Vec<GetEventResponse>,
+pub struct MintHooksResponse {
+    pub hooks: Vec<MintHook>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ListAttendeesResponse {
-    pub attendees: Vec<Attendee>,
+pub struct MintHook {
+    pub contract: String,
+    pub policy: HookPolicy,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct Attendee {
+pub struct MintHistogramResponse {
+    /// Oldest first
+    pub hours: Vec<MintHour>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintHour {
+    /// Unix timestamp of the start of the hour
+    pub start: u64,
+    pub badges: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttendedOccurrencesResponse {
+    /// Number of occurrences of the event
+    pub total: u32,
+    /// Ids of the occurrences the attendee was at
+    pub attended: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CheckInResponse {
+    pub checked_in_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RewardPoolResponse {
+    /// None if the event pays no rewards
+    pub payout: Option<Coin>,
+    pub balance: Uint128,
+    pub cw20: Option<Cw20RewardInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftTicketCollectionResponse {
+    pub collection: Option<String>,
+    pub burn: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RaffleResponse {
+    pub raffle: Option<Raffle>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GasBudgetResponse {
+    pub budget: Option<GasBudget>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeKeyResponse {
+    pub key: Option<BoundKey>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RedemptionResponse {
+    /// Block time of the redemption, None if the perk was not redeemed
+    pub redeemed_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20TicketTokenResponse {
+    pub token: Option<String>,
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20RewardInfo {
+    pub token: String,
+    pub amount: Uint128,
+    pub funder: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRatingResponse {
+    pub event: String,
+    /// Number of ratings submitted
+    pub count: u32,
+    /// Average score, zero if there are no ratings yet
+    pub average: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReservationInfo {
+    pub event: String,
+    pub reserved_by: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListReservationsResponse {
+    pub reservations: Vec<ReservationInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AllowlistedEventsResponse {
+    pub events: Vec<EventSummary>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TicketInfo {
+    pub event: String,
+    pub issued_by: String,
+    pub issued_to: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListTicketsResponse {
+    pub tickets: Vec<TicketInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimCodeResponse {
+    pub exists: bool,
+    pub claimed_by: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimSecretInfo {
+    pub hash: String,
+    pub window: ClaimWindow,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimSecretsResponse {
+    pub secrets: Vec<ClaimSecretInfo>,
+}
+
+/// The issuance fields are None for badges minted before provenance was tracked
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeProvenanceResponse {
+    pub event: String,
+    /// The current holder
     pub attendee: String,
-    pub was_late: bool,
+    /// Original recipient, if the badge was transferred
+    pub minted_to: Option<String>,
+    pub minted_by: Option<String>,
+    pub height: Option<u64>,
+    pub time: Option<u64>,
+    pub flow: Option<MintFlow>,
+    /// Why the badge was minted, if the minter said so
+    pub memo: Option<String>,
+    /// Self-claimed badges may be challenged until then
+    pub challenge_until: Option<u64>,
+    /// Name of the registered kiosk that minted the badge
+    pub kiosk: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct ListMyBadgesResponse {
-    pub badges: Vec<Badge>,
+pub struct UniqueHoldersResponse {
+    pub count: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct Badge {
+pub struct BadgeCountResponse {
+    pub attendee: String,
+    pub total: u32,
+    pub on_time: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HasBadgeResponse {
+    pub has_badge: bool,
+    pub expired: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GetSeriesResponse {
+    pub owner: String,
+    pub name: String,
+    pub description: String,
+    pub events: Vec<String>,
+    pub streak: Option<SeriesStreak>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AchievementResponse {
+    pub owner: String,
+    /// The event whose badge is claimed
     pub event: String,
-    pub was_late: bool,
+    pub required: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeriesStreakResponse {
+    pub length: u32,
+    /// Whether the attendee holds the series' streak badge
+    pub earned: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeriesProgressResponse {
+    pub series: String,
+    pub attendee: String,
+    /// Number of events in the series
+    pub total: u32,
+    /// Events in the series the attendee has a badge for
+    pub attended: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArchivedEventResponse {
+    /// None if the event was not archived
+    pub archive: Option<ArchivedEvent>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRecoveryResponse {
+    /// None if no recovery is pending
+    pub recovery: Option<EventRecovery>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArchivedEventInfo {
+    pub name: String,
+    pub archive: ArchivedEvent,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListArchivedEventsResponse {
+    pub events: Vec<ArchivedEventInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifyArchivedAttendeeResponse {
+    pub valid: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeClassesResponse {
+    pub classes: Vec<BadgeClassInfo>,
+    /// Badges held, of the event itself and all its classes
+    pub badge_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeClassInfo {
+    pub class: String,
+    /// The name of the event the badges of the class are minted in
+    pub event: String,
+    pub capacity: Option<u32>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub badge_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventAttendanceResponse {
+    /// Holds a badge of the event or any of its classes
+    pub attended: bool,
+    pub event_badge: bool,
+    /// The classes the attendee holds a badge of
+    pub classes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttendanceRootResponse {
+    /// None until the event is frozen
+    pub root: Option<AttendanceRoot>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttendanceProofResponse {
+    pub root: String,
+    /// Hex sha256 of the attendee address
+    pub leaf: String,
+    /// Hex siblings from the leaf to the root, hashed pairwise in sorted order
+    pub proof: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AirdropProofResponse {
+    pub airdrop: Airdrop,
+    /// The amount to claim, in the airdrop's denom
+    pub amount: Uint128,
+    /// Hex siblings from the attendee's leaf to the root, hashed pairwise in sorted order
+    pub proof: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoteBadgeResponse {
+    /// Verified against the root currently trusted for the event
+    pub verified: bool,
+    pub verified_at: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ForeignBadgeInfo {
+    pub address: String,
+    pub format: AddressFormat,
+    pub minted_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListForeignBadgesResponse {
+    pub badges: Vec<ForeignBadgeInfo>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ForeignAddressBadgesResponse {
+    pub events: Vec<String>,
+    /// Pass as `start_after` to get the next page, None on the last one
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OpenClaimResponse {
+    pub window: Option<ClaimWindow>,
+    /// Whether the window is running now
+    pub open: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WaitlistPositionResponse {
+    /// 1 for the first in line, None if the attendee is not waitlisted
+    pub position: Option<u32>,
+    /// Whether ClaimFromWaitlist would currently succeed
+    pub can_claim: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttendedResponse {
+    pub attended: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GateCondition {
+    /// A badge for every one of the events, in the tier if set and minted on time if on_time
+    AllOf {
+        events: Vec<String>,
+        #[serde(default)]
+        tier: Option<String>,
+        #[serde(default)]
+        on_time: bool,
+    },
+    /// A badge for at least one of the events, with the same filters
+    AnyOf {
+        events: Vec<String>,
+        #[serde(default)]
+        tier: Option<String>,
+        #[serde(default)]
+        on_time: bool,
+    },
+    /// At least this many badges across all events, expired ones included
+    MinBadges {
+        count: u32,
+        #[serde(default)]
+        on_time: bool,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CheckGateResponse {
+    /// Whether all conditions are met
+    pub passed: bool,
+    /// Indexes of the conditions that are met
+    pub matched: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CompletedSeriesResponse {
+    pub completed: bool,
 }
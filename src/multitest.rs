@@ -0,0 +1,274 @@
+//! Multi-test harness for integration tests against this contract.
+//! Enabled in downstream crates with the `test-utils` feature.
+
+use anyhow::Result as AnyResult;
+
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+use crate::contract::{execute, instantiate, query, reply};
+use crate::helpers::PoapContract;
+use crate::msg::{
+    ExecuteMsg, GetEventResponse, InstantiateMsg, ListMyBadgesResponse, MintBadgeMsg, QueryMsg,
+    RegisterEventMsg,
+};
+use crate::state::{BadgeFreezePolicy, RegistryMode};
+
+/// Address used to instantiate the contract, which also becomes the contract owner
+pub const ADMIN: &str = "admin";
+/// Address used to register the fixture events
+pub const EVENT_OWNER: &str = "organizer";
+
+pub fn contract_poap() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    Box::new(contract)
+}
+
+/// Event that started 100 seconds ago and ends in one hour, with all optional fields unset
+pub fn event_fixture(app: &App, name: &str) -> RegisterEventMsg {
+    let now = app.block_info().time.seconds();
+    RegisterEventMsg {
+        name: name.to_string(),
+        image: "https://example.com/badge.png".to_string(),
+        description: "Test event".to_string(),
+        metadata: None,
+        external_url: None,
+        start_time: now - 100,
+        end_time: now + 3600,
+        co_owners: vec![],
+        max_mints_per_block: None,
+        badge_valid_until: None,
+        claim_until: None,
+        min_duration: None,
+        allow_post_mint_edits: false,
+        capacity: None,
+        tiers: vec![],
+        animation_url: None,
+        background_color: None,
+        sha256: None,
+        timezone: None,
+        reward: None,
+        private: false,
+        publish_at: None,
+        venue: None,
+        location: None,
+        badge_freeze_policy: BadgeFreezePolicy::Holder,
+        extension: None,
+    }
+}
+
+/// Badge for an attendee who was on time, without note or occurrence
+pub fn mint_fixture(event: &str, attendee: &str) -> MintBadgeMsg {
+    MintBadgeMsg {
+        event: event.to_string(),
+        attendee: attendee.to_string(),
+        was_late: false,
+        note: None,
+        occurrence: None,
+        tier: None,
+        memo: None,
+        idempotent: false,
+        attributes: vec![],
+        image: None,
+    }
+}
+
+/// An App with the POAP contract instantiated, plus helpers for the common flows
+pub struct PoapSuite {
+    pub app: App,
+    pub code_id: u64,
+    pub contract: PoapContract,
+}
+
+impl PoapSuite {
+    /// Instantiate the contract with default config, owned by ADMIN
+    pub fn new() -> Self {
+        Self::with_msg(InstantiateMsg {
+            admin: None,
+            limits: None,
+            allow_insecure_urls: false,
+            registration_deposit: None,
+            require_approval: false,
+            registry_mode: RegistryMode::Open,
+            scoped_event_names: false,
+            collection: None,
+            deployment: None,
+        })
+    }
+
+    pub fn with_msg(msg: InstantiateMsg) -> Self {
+        let mut app = App::default();
+        let code_id = app.store_code(contract_poap());
+        let addr = app
+            .instantiate_contract(code_id, Addr::unchecked(ADMIN), &msg, &[], "poap", None)
+            .unwrap();
+        PoapSuite {
+            app,
+            code_id,
+            contract: PoapContract(addr),
+        }
+    }
+
+    pub fn addr(&self) -> Addr {
+        self.contract.addr()
+    }
+
+    /// Move the block time forward, along with the height
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+            block.height += seconds / 5 + 1;
+        });
+    }
+
+    pub fn execute(&mut self, sender: &str, msg: ExecuteMsg) -> AnyResult<AppResponse> {
+        self.execute_with_funds(sender, msg, &[])
+    }
+
+    pub fn execute_with_funds(
+        &mut self,
+        sender: &str,
+        msg: ExecuteMsg,
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), self.addr(), &msg, funds)
+    }
+
+    pub fn register_event(&mut self, owner: &str, msg: RegisterEventMsg) -> AnyResult<AppResponse> {
+        self.execute(owner, ExecuteMsg::RegisterEvent(msg))
+    }
+
+    /// Register event_fixture(name) as EVENT_OWNER
+    pub fn register_default_event(&mut self, name: &str) -> AnyResult<AppResponse> {
+        let msg = event_fixture(&self.app, name);
+        self.register_event(EVENT_OWNER, msg)
+    }
+
+    pub fn mint(&mut self, owner: &str, msg: MintBadgeMsg) -> AnyResult<AppResponse> {
+        self.execute(owner, ExecuteMsg::MintBadge(msg))
+    }
+
+    /// Reserve a badge as the event owner, to be claimed by the attendee
+    pub fn reserve(&mut self, owner: &str, event: &str, attendee: &str) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::ReserveBadge {
+            event: event.to_string(),
+            attendee: attendee.to_string(),
+        };
+        self.execute(owner, msg)
+    }
+
+    /// Claim a reserved badge as the attendee
+    pub fn claim(&mut self, attendee: &str, event: &str) -> AnyResult<AppResponse> {
+        let msg = ExecuteMsg::ClaimReserved {
+            event: event.to_string(),
+        };
+        self.execute(attendee, msg)
+    }
+
+    pub fn query_event(&self, name: &str) -> AnyResult<GetEventResponse> {
+        Ok(self.contract.event(&self.app.wrap(), name)?)
+    }
+
+    /// First page of the attendee's badges
+    pub fn query_badges(&self, attendee: &str) -> AnyResult<ListMyBadgesResponse> {
+        Ok(self
+            .contract
+            .badges(&self.app.wrap(), attendee, None, None)?)
+    }
+
+    pub fn query<T: serde::de::DeserializeOwned>(&self, msg: &QueryMsg) -> AnyResult<T> {
+        Ok(self.app.wrap().query_wasm_smart(self.addr(), msg)?)
+    }
+}
+
+impl Default for PoapSuite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::PoapQuerier;
+    use crate::msg::{GateCondition, HasBadgeResponse};
+    use crate::ContractError;
+
+    #[test]
+    fn mint_and_claim() {
+        let mut suite = PoapSuite::new();
+        suite.register_default_event("Hacker House").unwrap();
+
+        suite
+            .mint(EVENT_OWNER, mint_fixture("Hacker House", "alice"))
+            .unwrap();
+        suite.reserve(EVENT_OWNER, "Hacker House", "bob").unwrap();
+        assert!(suite.query_badges("bob").unwrap().badges.is_empty());
+        suite.claim("bob", "Hacker House").unwrap();
+
+        for attendee in ["alice", "bob"] {
+            let badges = suite.query_badges(attendee).unwrap().badges;
+            assert_eq!(badges.len(), 1);
+            assert_eq!(badges[0].event, "Hacker House");
+        }
+
+        let res: HasBadgeResponse = suite
+            .query(&QueryMsg::HasBadge {
+                event: "Hacker House".to_string(),
+                attendee: "carl".to_string(),
+                only_valid: false,
+                salt: None,
+            })
+            .unwrap();
+        assert!(!res.has_badge);
+    }
+
+    #[test]
+    fn poap_querier_checks_attendance() {
+        let mut suite = PoapSuite::new();
+        for event in ["Hacker House", "Demo Day"] {
+            suite.register_default_event(event).unwrap();
+            suite
+                .mint(EVENT_OWNER, mint_fixture(event, "alice"))
+                .unwrap();
+        }
+        let poap = suite.addr();
+        let querier = suite.app.wrap();
+
+        assert!(querier
+            .has_badge(&poap, "Hacker House", "alice", true)
+            .unwrap());
+        assert!(!querier
+            .has_badge(&poap, "Hacker House", "bob", true)
+            .unwrap());
+        assert_eq!(querier.badge_count(&poap, "alice").unwrap(), 2);
+        assert_eq!(querier.badge_count(&poap, "bob").unwrap(), 0);
+
+        let gate = vec![GateCondition::AllOf {
+            events: vec!["Hacker House".to_string(), "Demo Day".to_string()],
+            tier: None,
+            on_time: false,
+        }];
+        assert!(querier.check_gate(&poap, "alice", gate.clone()).unwrap());
+        assert!(!querier.check_gate(&poap, "bob", gate).unwrap());
+    }
+
+    #[test]
+    fn errors_come_back_as_contract_errors() {
+        let mut suite = PoapSuite::new();
+        suite.register_default_event("Hacker House").unwrap();
+
+        let err = suite
+            .mint("mallory", mint_fixture("Hacker House", "alice"))
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+        // once the event is over, no more badges
+        suite.advance_time(4000);
+        let err = suite
+            .mint(EVENT_OWNER, mint_fixture("Hacker House", "alice"))
+            .unwrap_err();
+        assert_eq!(ContractError::EventAlreadyOver, err.downcast().unwrap());
+    }
+}
@@ -0,0 +1,141 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use cosmwasm_std::{Addr, Api, BlockInfo, StdError, StdResult, Storage};
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+
+/// Contract-level ownership, following the semantics of cw-ownable.
+/// This is separate from the owners of the individual events.
+pub const OWNERSHIP: Item<Ownership<Addr>> = Item::new("ownership");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Ownership<T> {
+    /// The current owner. None if ownership was renounced
+    pub owner: Option<T>,
+    /// The account that was proposed as the next owner, if any
+    pub pending_owner: Option<T>,
+    /// Deadline for the pending owner to accept the transfer
+    pub pending_expiry: Option<Expiration>,
+}
+
+impl Ownership<Addr> {
+    pub fn assert_owner(&self, sender: &Addr) -> Result<(), OwnershipError> {
+        match &self.owner {
+            None => Err(OwnershipError::NoOwner),
+            Some(owner) if owner != sender => Err(OwnershipError::NotOwner),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn into_response(self) -> Ownership<String> {
+        Ownership {
+            owner: self.owner.map(String::from),
+            pending_owner: self.pending_owner.map(String::from),
+            pending_expiry: self.pending_expiry,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Propose a new owner. The transfer only happens once they accept it
+    TransferOwnership {
+        new_owner: String,
+        expiry: Option<Expiration>,
+    },
+    /// Called by the pending owner to complete the transfer
+    AcceptOwnership,
+    /// Give up ownership for good, along with any pending transfer
+    RenounceOwnership,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnershipError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Contract ownership has been renounced")]
+    NoOwner,
+
+    #[error("Caller is not the contract's current owner")]
+    NotOwner,
+
+    #[error("Caller is not the contract's pending owner")]
+    NotPendingOwner,
+
+    #[error("There isn't a pending ownership transfer")]
+    TransferNotFound,
+
+    #[error("A pending ownership transfer exists but it has expired")]
+    TransferExpired,
+
+    #[error("Ownership transfer expiry is already in the past")]
+    InvalidExpiry,
+}
+
+pub fn initialize_owner(storage: &mut dyn Storage, owner: Addr) -> StdResult<()> {
+    OWNERSHIP.save(
+        storage,
+        &Ownership {
+            owner: Some(owner),
+            pending_owner: None,
+            pending_expiry: None,
+        },
+    )
+}
+
+pub fn get_ownership(storage: &dyn Storage) -> StdResult<Ownership<Addr>> {
+    OWNERSHIP.load(storage)
+}
+
+pub fn assert_owner(storage: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+    get_ownership(storage)?.assert_owner(sender)
+}
+
+pub fn update_ownership(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    action: Action,
+) -> Result<Ownership<Addr>, OwnershipError> {
+    let mut ownership = get_ownership(storage)?;
+    match action {
+        Action::TransferOwnership { new_owner, expiry } => {
+            ownership.assert_owner(sender)?;
+            if let Some(expiry) = &expiry {
+                if expiry.is_expired(block) {
+                    return Err(OwnershipError::InvalidExpiry);
+                }
+            }
+            ownership.pending_owner = Some(api.addr_validate(&new_owner)?);
+            ownership.pending_expiry = expiry;
+        }
+        Action::AcceptOwnership => {
+            let pending = ownership
+                .pending_owner
+                .take()
+                .ok_or(OwnershipError::TransferNotFound)?;
+            if &pending != sender {
+                return Err(OwnershipError::NotPendingOwner);
+            }
+            if let Some(expiry) = ownership.pending_expiry.take() {
+                if expiry.is_expired(block) {
+                    return Err(OwnershipError::TransferExpired);
+                }
+            }
+            ownership.owner = Some(pending);
+        }
+        Action::RenounceOwnership => {
+            ownership.assert_owner(sender)?;
+            ownership.owner = None;
+            ownership.pending_owner = None;
+            ownership.pending_expiry = None;
+        }
+    }
+    OWNERSHIP.save(storage, &ownership)?;
+    Ok(ownership)
+}
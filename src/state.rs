@@ -1,26 +1,1286 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::Addr;
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Attribute, Binary, Coin, Decimal, Empty, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+use cw_utils::Expiration;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const DEPLOYMENT_INFO: Item<DeploymentInfo> = Item::new("deployment_info");
+/// Storage key of the counter `Item<State>` from the cw-template this contract started from.
+/// Never read, only removed by migrate
+pub const LEGACY_STATE_KEY: &[u8] = b"state";
+/// Progress of a migration spanning several transactions, removed once it is complete
+pub const MIGRATION: Item<MigrationStep> = Item::new("migration");
+/// Version of the storage layout, which migrate converts from. Not stored by the contracts
+/// from before it, which are at 0
+pub const STORAGE_LAYOUT: Item<u32> = Item::new("storage_layout");
 
 pub const EVENTS: Map<&str, EventData> = Map::new("events");
+// descriptive fields, only loaded by queries so mints don't pay for them
+pub const EVENT_METADATA: Map<&str, EventMetadata> = Map::new("event_metadata");
+/// Before EVENT_METADATA, the descriptive fields were stored in EVENTS. Only read by migrate
+pub const LEGACY_EVENT_METADATA: Map<&str, EventMetadata> = Map::new("events");
+
+pub struct BadgeIndexes<'a> {
+    // all badges of an attendee, across events
+    pub attendee: MultiIndex<'a, Addr, BadgeData, (String, Addr)>,
+}
+
+impl<'a> IndexList<BadgeData> for BadgeIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BadgeData>> + '_> {
+        let v: Vec<&dyn Index<BadgeData>> = vec![&self.attendee];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Badges keyed by (event name, attendee address), indexed by attendee
+pub fn badges<'a>() -> IndexedMap<'a, (&'a str, &'a Addr), BadgeData, BadgeIndexes<'a>> {
+    let indexes = BadgeIndexes {
+        attendee: MultiIndex::new(
+            |badge: &BadgeData| badge.attendee.clone(),
+            "attendees",
+            "attendees__attendee",
+        ),
+    };
+    IndexedMap::new("attendees", indexes)
+}
+
+/// Badges used to be written twice, under (event, attendee) in "attendees" and under
+/// (attendee, event) in "badges", without the attendee in the value. Only read by migrate
+pub const LEGACY_BADGES: Map<(&Addr, &str), LegacyBadgeData> = Map::new("badges");
+
+// number of distinct addresses holding at least one badge
+pub const UNIQUE_HOLDERS: Item<u64> = Item::new("unique_holders");
+// change feed: id -> change, with ids assigned in order
+pub const CHANGES: Map<u64, ChangeEntry> = Map::new("changes");
+pub const CHANGE_COUNT: Item<u64> = Item::new("change_count");
+// block height -> id of the first change at that height
+pub const CHANGE_HEIGHTS: Map<u64, u64> = Map::new("change_heights");
+// ring buffer of the last RECENT_MINTS_SIZE mints across events: sequence number -> mint.
+// Older entries are removed as new ones come in
+pub const RECENT_MINTS: Map<u64, RecentMint> = Map::new("recent_mints");
+// (event name, start of the hour) -> badges minted in the event during that hour
+pub const MINT_HISTOGRAM: Map<(&str, u64), u32> = Map::new("mint_histogram");
+// holder -> display nickname shown in attendee listings
+pub const NICKNAMES: Map<&Addr, String> = Map::new("nicknames");
+// (sender, event name) -> the rest of a partial MintBadges batch
+pub const MINT_BATCHES: Map<(&Addr, &str), MintBatch> = Map::new("mint_batches");
+// (event name, hook contract) -> what a failure of the hook does to the mint
+pub const MINT_HOOKS: Map<(&str, &Addr), HookPolicy> = Map::new("mint_hooks");
+pub const RECENT_MINT_COUNT: Item<u64> = Item::new("recent_mint_count");
+// bounded log of privileged actions: sequence number -> entry. The oldest entries are removed
+// past AUDIT_LOG_SIZE
+pub const AUDIT_LOG: Map<u64, AuditEntry> = Map::new("audit_log");
+pub const AUDIT_LOG_COUNT: Item<u64> = Item::new("audit_log_count");
+// event name -> serial number of the last badge minted
+pub const EVENT_SERIALS: Map<&str, u32> = Map::new("event_serials");
+// (event name, referrer) -> badges held that were claimed with the referrer, for Referrals
+pub const REFERRAL_COUNTS: Map<(&str, &Addr), u32> = Map::new("referral_counts");
+// event name -> badges currently held
+pub const EVENT_BADGE_COUNTS: Map<&str, u64> = Map::new("event_badge_counts");
+// badges currently held, across all events
+pub const TOTAL_BADGES: Item<u64> = Item::new("total_badges");
+// (owner, end time, event name), to find an organizer's next event
+pub const OWNER_EVENTS: Map<(&Addr, u64, &str), Empty> = Map::new("owner_events");
+// (start time, event name), for listings by start time
+pub const EVENTS_BY_START: Map<(u64, &str), Empty> = Map::new("events_by_start");
+// (registration time, event name), for listings by registration. Zero for events registered
+// before it was recorded
+pub const EVENTS_BY_REGISTRATION: Map<(u64, &str), Empty> = Map::new("events_by_registration");
+// (badges held, event name), for listings by popularity
+pub const EVENTS_BY_BADGES: Map<(u64, &str), Empty> = Map::new("events_by_badges");
+// (latitude cell, longitude cell, event name) of the events with a location, in one degree
+// cells, for EventsInArea
+pub const EVENTS_BY_AREA: Map<(i16, i16, &str), Empty> = Map::new("events_by_area");
+// owner -> events registered and badges minted into them, for OrganizerStats
+pub const ORGANIZER_COUNTS: Map<&Addr, OrganizerCounts> = Map::new("organizer_counts");
+// (event name, address) of the challenged claims. The address may not self-claim again
+pub const CHALLENGED_CLAIMS: Map<(&str, &Addr), Empty> = Map::new("challenged_claims");
+// (event name, revision number) -> metadata edit. Only the last EVENT_HISTORY_SIZE are kept
+pub const EVENT_HISTORY: Map<(&str, u32), EventRevision> = Map::new("event_history");
+// event name -> number of metadata edits so far
+pub const EVENT_REVISIONS: Map<&str, u32> = Map::new("event_revisions");
+// (event name, sequence number) -> address, in the order they joined the waitlist
+pub const WAITLIST: Map<(&str, u64), Addr> = Map::new("waitlist");
+// (event name, address) -> sequence number in WAITLIST
+pub const WAITLIST_ENTRIES: Map<(&str, &Addr), u64> = Map::new("waitlist_entries");
+// event name -> next waitlist sequence number
+pub const WAITLIST_SEQ: Map<&str, u64> = Map::new("waitlist_seq");
+// (claim deadline, event name) of the events EndOfBlock has not finalized yet
+pub const FINALIZE_QUEUE: Map<(u64, &str), Empty> = Map::new("finalize_queue");
+// attendee address -> badges held. Removed once the attendee holds none
+pub const BADGE_COUNTS: Map<&Addr, BadgeCount> = Map::new("badge_counts");
+
+// (event name, address) -> role on the event, for anyone but its owners
+pub const EVENT_ROLES: Map<(&str, &Addr), RoleGrant> = Map::new("event_roles");
+// (event name, minter address) -> expiry of the minter grants from before EVENT_ROLES. Moved
+// on migration
+pub const LEGACY_MINTERS: Map<(&str, &Addr), Expiration> = Map::new("minters");
+// (event name, holder, spender), cleared when the badge changes hands
+pub const BADGE_APPROVALS: Map<(&str, &Addr, &Addr), Expiration> = Map::new("badge_approvals");
+// (holder, operator) for all badges of the holder
+pub const OPERATORS: Map<(&Addr, &Addr), Expiration> = Map::new("operators");
+
+// (event name, hex sha256 of a one-time claim code)
+pub const CLAIM_CODES: Map<(&str, &str), ClaimCode> = Map::new("claim_codes");
+
+// (event name, kiosk address) -> the nonce currently displayed by the kiosk
+pub const KIOSK_NONCES: Map<(&str, &Addr), KioskNonce> = Map::new("kiosk_nonces");
+// (event name, hex sha256 of the secret) -> when the rotating claim secret may be revealed
+pub const CLAIM_SECRETS: Map<(&str, &str), ClaimWindow> = Map::new("claim_secrets");
+// (event name, kiosk address) -> check-in station registered by the organizers
+pub const EVENT_KIOSKS: Map<(&str, &Addr), Kiosk> = Map::new("event_kiosks");
+
+// (event name, attendee address) -> check-in timestamp, until they check out
+pub const CHECK_INS: Map<(&str, &Addr), u64> = Map::new("check_ins");
+
+// event name -> funds paid out to new badge holders
+pub const REWARD_POOLS: Map<&str, RewardPool> = Map::new("reward_pools");
+// event name -> cw20 tokens sent to new badge holders from the funder's allowance
+pub const CW20_REWARDS: Map<&str, Cw20Reward> = Map::new("cw20_rewards");
+// event name -> cw721 collection whose tickets are converted into badges
+pub const NFT_TICKET_COLLECTIONS: Map<&str, NftTicketCollection> =
+    Map::new("nft_ticket_collections");
+// event name -> cw20 token redeemed for badges
+pub const CW20_TICKET_TOKENS: Map<&str, Cw20TicketToken> = Map::new("cw20_ticket_tokens");
+// (event name, sponsor) -> sponsorship
+pub const SPONSORS: Map<(&str, &Addr), Sponsorship> = Map::new("sponsors");
+pub const GAS_BUDGETS: Map<&str, GasBudget> = Map::new("gas_budgets");
+/// When perks were redeemed, by (event, original recipient of the badge, perk id). Keyed by
+/// the original recipient so a transferred badge can't redeem the same perk again
+pub const REDEMPTIONS: Map<(&str, &Addr, &str), u64> = Map::new("redemptions");
+/// Expiration of the fee allowance the contract granted to the attendee. The chain accepts
+/// only one allowance per granter and grantee
+pub const FEE_GRANTS: Map<&Addr, u64> = Map::new("fee_grants");
+// event name -> raffle among its badge holders
+pub const RAFFLES: Map<&str, Raffle> = Map::new("raffles");
+// organizers allowed to register in allowlisted mode
+pub const ORGANIZERS: Map<&Addr, Empty> = Map::new("organizers");
+// organizer -> max_active_events set by the admin for them, replacing the configured one
+pub const ORGANIZER_EVENT_LIMITS: Map<&Addr, u32> = Map::new("organizer_event_limits");
+
+// plan name -> plan, set by the admin
+pub const SUBSCRIPTION_PLANS: Map<&str, SubscriptionPlan> = Map::new("subscription_plans");
+pub const SUBSCRIPTIONS: Map<&Addr, Subscription> = Map::new("subscriptions");
+
+// (organizer, template name) -> template
+pub const EVENT_TEMPLATES: Map<(&Addr, &str), EventTemplate> = Map::new("event_templates");
+
+pub const EVENT_COUNCILS: Map<&str, EventCouncil> = Map::new("event_councils");
+// (event name, proposal id)
+pub const EVENT_PROPOSALS: Map<(&str, u64), EventProposal> = Map::new("event_proposals");
+pub const EVENT_PROPOSAL_SEQ: Map<&str, u64> = Map::new("event_proposal_seq");
+// (event name, address) -> reason the address flagged the event
+pub const FLAGS: Map<(&str, &Addr), String> = Map::new("flags");
+// event name -> flags since the last review
+pub const FLAG_COUNTS: Map<&str, u32> = Map::new("flag_counts");
+// events waiting for the admin's approval
+pub const PENDING_EVENTS: Map<&str, Empty> = Map::new("pending_events");
+// event name -> registration deposit, until refunded or forfeited
+pub const DEPOSITS: Map<&str, Deposit> = Map::new("deposits");
+// denom -> total held in reward pools and deposits, which the admin can't sweep
+pub const POOLED_FUNDS: Map<&str, Uint128> = Map::new("pooled_funds");
+// denom -> price of an event registration, set by the admin. Any priced denom is accepted
+pub const REGISTRATION_FEES: Map<&str, Uint128> = Map::new("registration_fees");
+// denom -> price of a badge minted by staff, set by the admin
+pub const MINT_FEES: Map<&str, Uint128> = Map::new("mint_fees");
+// registration fee discounts by completed events, set by the admin. Sorted by completed_events
+pub const FEE_DISCOUNTS: Item<Vec<FeeDiscount>> = Item::new("fee_discounts");
+
+// event name -> (block height, mints in that block), used for rate limiting
+pub const BLOCK_MINTS: Map<&str, (u64, u32)> = Map::new("block_mints");
+
+// (event name, language tag)
+pub const TRANSLATIONS: Map<(&str, &str), Translation> = Map::new("translations");
+
+// (attendee address, event name) -> reservation, until the attendee claims it
+pub const RESERVATIONS: Map<(&Addr, &str), Reservation> = Map::new("reservations");
+// where the last Cleanup stopped in each map it sweeps
+pub const CLEANUP_CURSOR: Item<CleanupCursor> = Item::new("cleanup_cursor");
+
+// (holder address, event name)
+pub const TICKETS: Map<(&Addr, &str), Ticket> = Map::new("tickets");
+// event name -> tickets not yet checked in, counted against the capacity
+pub const EVENT_TICKET_COUNTS: Map<&str, u32> = Map::new("event_ticket_counts");
+// event name -> window during which anyone may claim a badge with OpenClaim
+pub const OPEN_CLAIMS: Map<&str, ClaimWindow> = Map::new("open_claims");
+// event name -> transfer announced by the admin, until it is completed or cancelled
+pub const EVENT_RECOVERIES: Map<&str, EventRecovery> = Map::new("event_recoveries");
+// event name -> archive record, once its minting state was pruned
+pub const ARCHIVED_EVENTS: Map<&str, ArchivedEvent> = Map::new("archived_events");
+// event name -> merkle root over its holders, taken when the event was frozen
+pub const ATTENDANCE_ROOTS: Map<&str, AttendanceRoot> = Map::new("attendance_roots");
+// event name -> the holders committed to by its attendance root, in address order
+pub const ATTENDANCE_SNAPSHOTS: Map<&str, Vec<Addr>> = Map::new("attendance_snapshots");
+// (event name, airdrop contract) -> the airdrop handed off to it, one per contract
+pub const AIRDROPS: Map<(&str, &Addr), Airdrop> = Map::new("airdrops");
+// (chain id, remote event name) -> hex attendance root trusted by the admin
+pub const REMOTE_ROOTS: Map<(&str, &str), String> = Map::new("remote_roots");
+// (chain id, remote event name, remote address) -> badge proven against the remote root
+pub const REMOTE_BADGES: Map<(&str, &str, &str), RemoteBadge> = Map::new("remote_badges");
+// (event name, hex sha256 of the salt and attendee) -> mint timestamp, for private events
+pub const PRIVATE_BADGES: Map<(&str, &str), u64> = Map::new("private_badges");
+// (event name, foreign address) -> badge minted to an address of another chain
+pub const FOREIGN_BADGES: Map<(&str, &str), ForeignBadge> = Map::new("foreign_badges");
+// (foreign address, event name), to list the badges of a foreign address
+pub const FOREIGN_HOLDINGS: Map<(&str, &str), Empty> = Map::new("foreign_holdings");
+
 // (event name, attendee address)
-pub const ATTENDEES: Map<(&str, &Addr), BadgeData> = Map::new("attendees");
-// (attendee address, event name)
-pub const BADGES: Map<(&Addr, &str), BadgeData> = Map::new("badges");
+pub const RATINGS: Map<(&str, &Addr), Rating> = Map::new("ratings");
+pub const RATING_TOTALS: Map<&str, RatingTotals> = Map::new("rating_totals");
+
+// (event name, occurrence id)
+pub const OCCURRENCES: Map<(&str, u32), OccurrenceData> = Map::new("occurrences");
+// (event name, attendee address, occurrence id)
+pub const OCCURRENCE_ATTENDEES: Map<(&str, &Addr, u32), BadgeData> =
+    Map::new("occurrence_attendees");
+
+// (event name, class name) of the badge classes of an event. Each is an event of its own,
+// named as badge_class_id returns
+pub const BADGE_CLASSES: Map<(&str, &str), Empty> = Map::new("badge_classes");
+
+pub const SERIES: Map<&str, SeriesData> = Map::new("series");
+// (series name, event name)
+pub const SERIES_EVENTS: Map<(&str, &str), Empty> = Map::new("series_events");
+// (event name, series name) for the series with a streak badge, checked on every mint
+// achievement id -> achievement
+pub const ACHIEVEMENTS: Map<&str, Achievement> = Map::new("achievements");
+pub const STREAK_EVENTS: Map<(&str, &str), Empty> = Map::new("streak_events");
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct Config {
+    pub limits: ValidationLimits,
+    /// Accept http:// as well as https:// URLs (dev mode)
+    pub allow_insecure_urls: bool,
+    /// Escrowed with every event registration, refunded once the event is over
+    pub registration_deposit: Option<Coin>,
+    /// Curated mode: new events must be approved by the admin before badges can be minted
+    #[serde(default)]
+    pub require_approval: bool,
+    /// Who may register events and series. Fixed at instantiation
+    #[serde(default)]
+    pub registry_mode: RegistryMode,
+    /// Events are stored under `<owner>/<name>`. Fixed at instantiation
+    #[serde(default)]
+    pub scoped_event_names: bool,
+    /// Events are paused for review once this many addresses flagged them
+    #[serde(default)]
+    pub flag_threshold: Option<u32>,
+    /// If not empty, image URLs must be hosted on one of these hostnames
+    #[serde(default)]
+    pub allowed_image_domains: Vec<String>,
+    /// Shown by wallets that treat the contract as a cw721 collection
+    #[serde(default)]
+    pub collection: CollectionInfo,
+    /// Resolves attendee names (like `alice.osmo`) to addresses
+    #[serde(default)]
+    pub name_registry: Option<Addr>,
+    /// Provides the randomness of raffles
+    #[serde(default)]
+    pub nois_proxy: Option<Addr>,
+    /// How many events that haven't ended an organizer may have at once, unless the admin
+    /// set another limit for them. The admin is not limited
+    #[serde(default)]
+    pub max_active_events: Option<u32>,
+    /// Badges a partial MintBadges or ContinueBatch mints per message, the default if unset
+    #[serde(default)]
+    pub batch_mint_limit: Option<u32>,
+}
+
+/// How explorers label this deployment, to tell several POAP contracts apart
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct EventData {
-    pub owner: Addr,
+pub struct DeploymentInfo {
+    pub name: String,
+    pub description: String,
+    /// Logo or banner URL
+    pub image: Option<String>,
+}
+
+/// Same shape as cw721's ContractInfoResponse
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CollectionInfo {
     pub name: String,
+    pub symbol: String,
+}
+
+impl Default for CollectionInfo {
+    fn default() -> Self {
+        CollectionInfo {
+            name: "DSRV POAP".to_string(),
+            symbol: "POAP".to_string(),
+        }
+    }
+}
+
+/// Order of the event listings. Ties are broken by name
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSort {
+    #[default]
+    Name,
+    StartTime,
+    /// Order of registration
+    Registration,
+    /// Badges currently held, which may change between pages
+    Badges,
+}
+
+/// What a failing mint hook does
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HookPolicy {
+    /// The mint is reverted, eg. for hooks keeping a required ledger in sync
+    Strict,
+    /// The failure is logged and the mint goes through
+    FireAndForget,
+}
+
+/// Who may freeze the metadata of a badge of the event
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BadgeFreezePolicy {
+    #[default]
+    Holder,
+    /// The event admins too, eg. to seal the badges once the artwork is final
+    HolderOrAdmins,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryMode {
+    /// Anyone may register
+    #[default]
+    Open,
+    /// Only organizers approved by the admin (and the admin)
+    Allowlisted,
+    /// Only the admin
+    AdminOnly,
+    /// Organizers with an active subscription, within the limits of their plan (and the admin)
+    Subscribers,
+}
+
+/// Everything needed to register an event but its name and times. Deadlines are relative to
+/// the end of the event
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventTemplate {
     pub image: String,
     pub description: String,
+    pub metadata: Option<String>,
+    pub external_url: Option<String>,
+    #[serde(default)]
+    pub co_owners: Vec<String>,
+    pub max_mints_per_block: Option<u32>,
+    /// Badges expire this many seconds after the end of the event
+    pub badge_validity: Option<u64>,
+    /// Badges can still be minted this many seconds after the end of the event
+    pub claim_period: Option<u64>,
+    pub min_duration: Option<u64>,
+    #[serde(default)]
+    pub allow_post_mint_edits: bool,
+    #[serde(default)]
+    pub capacity: Option<u32>,
+    #[serde(default)]
+    pub tiers: Vec<Tier>,
+    pub animation_url: Option<String>,
+    pub background_color: Option<String>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SubscriptionPlan {
+    /// Paid for every period of 30 days
+    pub price: Coin,
+    /// Events that can be registered per period
+    pub max_events: u32,
+    /// Highest capacity events can be given. If set, events must have a capacity
+    pub max_capacity: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Subscription {
+    pub plan_name: String,
+    /// Copied when subscribing, so later changes to the plan apply on renewal
+    pub plan: SubscriptionPlan,
+    pub expires: u64,
+    /// Start of the current period, for the events quota
+    pub period_start: u64,
+    pub events_registered: u32,
+}
+
+/// Bounds applied to user-provided event metadata
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ValidationLimits {
+    pub min_name_length: u32,
+    pub max_name_length: u32,
+    pub max_description_length: u32,
+    pub max_image_url_length: u32,
+    pub max_metadata_length: u32,
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        ValidationLimits {
+            min_name_length: 2,
+            max_name_length: 100,
+            max_description_length: 1000,
+            max_image_url_length: 512,
+            max_metadata_length: 2048,
+        }
+    }
+}
+
+/// Everything needed to validate a mint. The name is the key
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventData {
+    pub owner: Addr,
+    #[serde(default)]
+    pub co_owners: Vec<Addr>,
     pub start_time: u64,
     pub end_time: u64,
+    #[serde(default)]
+    pub frozen: bool,
+    pub max_mints_per_block: Option<u32>,
+    /// Badges for this event expire after this timestamp (eg. yearly memberships)
+    pub badge_valid_until: Option<u64>,
+    /// Number of occurrences registered for a recurring event, used to assign ids
+    #[serde(default)]
+    pub occurrences: u32,
+    /// Badges can still be minted after end_time until this timestamp
+    pub claim_until: Option<u64>,
+    /// Holders may transfer their badges. Badges are soulbound unless the owner enables this
+    #[serde(default)]
+    pub transferable: bool,
+    /// Badges are only minted on check-out, after this many seconds of attendance
+    pub min_duration: Option<u64>,
+    /// Waiting for the admin's approval (curated mode)
+    #[serde(default)]
+    pub pending: bool,
+    /// Metadata may still be edited once badges were minted
+    #[serde(default)]
+    pub allow_post_mint_edits: bool,
+    /// Maximum number of badges held at once. Burned badges free their slot
+    #[serde(default)]
+    pub capacity: Option<u32>,
+    /// Self-claimed badges stay provisional for this many seconds, during which admins may
+    /// challenge them
+    #[serde(default)]
+    pub challenge_period: Option<u64>,
+    /// Set when the owner ended the event before end_time, which keeps the scheduled end
+    #[serde(default)]
+    pub closed_at: Option<u64>,
+    /// Only hashes of the attendees are stored, minted with MintPrivateBadge
+    #[serde(default)]
+    pub private: bool,
+    /// Cancelled by the owner: frozen, with sponsors refunded
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Hidden from public listings, and closed to minting, until this timestamp
+    #[serde(default)]
+    pub publish_at: Option<u64>,
+    /// Minting is suspended until the owners resume it (eg. during a scanner outage)
+    #[serde(default)]
+    pub paused: bool,
+    /// Block time of the registration, zero for events registered before it was recorded
+    #[serde(default)]
+    pub registered_at: u64,
+    /// Name of the place the event is held at, for display
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// Coordinates of the venue, indexed for EventsInArea
+    #[serde(default)]
+    pub location: Option<GeoPoint>,
+    /// The event this is a badge class of
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Who may freeze the metadata of a badge
+    #[serde(default)]
+    pub badge_freeze_policy: BadgeFreezePolicy,
+    /// Opaque payload attached by other applications, never interpreted
+    #[serde(default)]
+    pub extension: Option<Binary>,
+}
+
+/// A point on the map, in millionths of a degree
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+pub struct GeoPoint {
+    /// -90_000_000 (south pole) to 90_000_000 (north pole)
+    pub lat: i32,
+    /// -180_000_000 to 180_000_000, east of Greenwich is positive
+    pub lon: i32,
+}
+
+impl GeoPoint {
+    /// The one degree cell of the point, as keyed in EVENTS_BY_AREA
+    pub fn cell(&self) -> (i16, i16) {
+        (
+            self.lat.div_euclid(1_000_000) as i16,
+            self.lon.div_euclid(1_000_000) as i16,
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventMetadata {
+    pub image: String,
+    pub description: String,
+    /// Optional JSON document with additional structured metadata
+    pub metadata: Option<String>,
+    /// Event page, ticketing link or governance proposal
+    pub external_url: Option<String>,
+    /// Badge tiers (eg. speaker, attendee), each with optional artwork
+    #[serde(default)]
+    pub tiers: Vec<Tier>,
+    /// Animated artwork or video, shown by galleries instead of the image
+    #[serde(default)]
+    pub animation_url: Option<String>,
+    /// Six hex digits without the "#", used as the artwork background by galleries
+    #[serde(default)]
+    pub background_color: Option<String>,
+    /// Lowercase hex sha256 of the hosted image or metadata file
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// UTC offset or IANA timezone the event times should be displayed in
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Tier {
+    pub name: String,
+    /// Replaces the event image on badges of this tier
+    pub image: Option<String>,
+    /// Limited edition: badges minted without a tier get the first tier whose max_serial is
+    /// at least their serial number (eg. 100 for the first 100 claimants)
+    #[serde(default)]
+    pub max_serial: Option<u32>,
+}
+
+impl EventMetadata {
+    /// The artwork of a badge: its tier's image if it has one, otherwise the event image
+    pub fn badge_image(&self, tier: Option<&str>) -> &str {
+        tier.and_then(|tier| self.tiers.iter().find(|t| t.name == tier))
+            .and_then(|t| t.image.as_deref())
+            .unwrap_or(&self.image)
+    }
+
+    /// The badge's own artwork, or the image of its tier
+    pub fn image_of<'a>(&'a self, badge: &'a BadgeData) -> &'a str {
+        badge
+            .image
+            .as_deref()
+            .unwrap_or_else(|| self.badge_image(badge.tier.as_deref()))
+    }
+
+    /// The fields UpdateEventMetadata may edit that differ from `previous`
+    pub fn changes_from(&self, previous: &EventMetadata) -> Vec<MetadataChange> {
+        let fields = [
+            ("image", Some(&previous.image), Some(&self.image)),
+            (
+                "description",
+                Some(&previous.description),
+                Some(&self.description),
+            ),
+            (
+                "metadata",
+                previous.metadata.as_ref(),
+                self.metadata.as_ref(),
+            ),
+            (
+                "external_url",
+                previous.external_url.as_ref(),
+                self.external_url.as_ref(),
+            ),
+            (
+                "animation_url",
+                previous.animation_url.as_ref(),
+                self.animation_url.as_ref(),
+            ),
+            (
+                "background_color",
+                previous.background_color.as_ref(),
+                self.background_color.as_ref(),
+            ),
+            ("sha256", previous.sha256.as_ref(), self.sha256.as_ref()),
+            (
+                "timezone",
+                previous.timezone.as_ref(),
+                self.timezone.as_ref(),
+            ),
+        ];
+        fields
+            .iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| MetadataChange {
+                field: field.to_string(),
+                previous: old.cloned(),
+                value: new.cloned(),
+            })
+            .collect()
+    }
+}
+
+/// One field changed by UpdateEventMetadata
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetadataChange {
+    pub field: String,
+    pub previous: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRevision {
+    pub editor: Addr,
+    pub height: u64,
+    pub time: u64,
+    pub changes: Vec<MetadataChange>,
+}
+
+/// Owners of the event whose approvals are needed, by weight, for sensitive actions
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventCouncil {
+    pub members: Vec<CouncilMember>,
+    pub threshold: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CouncilMember {
+    pub addr: Addr,
+    pub weight: u64,
+}
+
+impl EventCouncil {
+    pub fn weight_of(&self, addr: &Addr) -> u64 {
+        self.members
+            .iter()
+            .find(|member| &member.addr == addr)
+            .map(|member| member.weight)
+            .unwrap_or_default()
+    }
+}
+
+/// Actions on an event that need the approval of its council, once it has one
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventAction {
+    Freeze {},
+    AddCoOwner {
+        co_owner: String,
+    },
+    RemoveCoOwner {
+        co_owner: String,
+    },
+    /// Replace the members and threshold of the council
+    SetCouncil {
+        members: Vec<Member>,
+        threshold: u64,
+    },
+    /// Go back to co-owners having equal rights
+    RemoveCouncil {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Member {
+    pub addr: String,
+    pub weight: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventProposal {
+    pub action: EventAction,
+    pub approvals: Vec<Addr>,
+}
+
+impl EventData {
+    /// The original owner and all co-owners have equal rights on the event
+    pub fn is_owner(&self, addr: &Addr) -> bool {
+        &self.owner == addr || self.co_owners.contains(addr)
+    }
+
+    /// When the event actually ended: end_time, unless it was closed early
+    pub fn effective_end(&self) -> u64 {
+        self.closed_at.unwrap_or(self.end_time)
+    }
+
+    /// Last moment a badge can be minted for the event. Closing it early also closes the
+    /// claim window
+    pub fn claim_deadline(&self) -> u64 {
+        match self.closed_at {
+            Some(closed_at) => closed_at,
+            None => self.claim_until.unwrap_or(self.end_time),
+        }
+    }
+
+    /// Scheduled events are published at publish_at, the others as soon as they are registered
+    pub fn is_published(&self, now: u64) -> bool {
+        self.publish_at.is_none_or(|publish_at| now >= publish_at)
+    }
+
+    /// First moment a badge can be minted: the start, or the publication if it is later
+    pub fn mint_start(&self) -> u64 {
+        self.start_time.max(self.publish_at.unwrap_or_default())
+    }
+
+    /// Where the event is in its lifecycle at `now`. Archived events are tracked outside
+    /// EventData, so the caller says whether it is
+    pub fn status(&self, now: u64, archived: bool) -> EventStatus {
+        if archived {
+            EventStatus::Archived
+        } else if self.cancelled {
+            EventStatus::Cancelled
+        } else if self.frozen {
+            EventStatus::Frozen
+        } else if self.pending || !self.is_published(now) {
+            EventStatus::Draft
+        } else if now < self.start_time {
+            EventStatus::Published
+        } else if now < self.effective_end() {
+            EventStatus::Active
+        } else {
+            EventStatus::Ended
+        }
+    }
+}
+
+/// Lifecycle of an event, from the stored flags and the block time. Each status only moves
+/// forward: Draft, Published, Active and Ended follow each other, and Cancelled, Frozen and
+/// Archived are final
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventStatus {
+    /// Waiting for the admin's approval in curated mode, or for its publication time
+    Draft,
+    /// Not started yet
+    Published,
+    /// Between the start and the (possibly early) end
+    Active,
+    /// Over, badges may still be claimed until the claim deadline
+    Ended,
+    /// Cancelled by the owner, sponsors refunded
+    Cancelled,
+    /// Finalized or frozen by the owners, nothing can be minted anymore
+    Frozen,
+    Archived,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct BadgeData {
+    /// Duplicated from the key, as the attendee index is built from the value
+    pub attendee: Addr,
+    pub was_late: bool,
+    pub note: Option<String>,
+    /// Copied from the event at mint time. The badge is expired after this timestamp
+    pub valid_until: Option<u64>,
+    /// The address the badge was originally minted to, if it was transferred since
+    pub minted_to: Option<Addr>,
+    /// Seconds between check-in and check-out, for events with min_duration
+    pub duration: Option<u64>,
+    /// How the badge was issued. None for badges minted before this was tracked
+    pub provenance: Option<Provenance>,
+    /// One of the event's tiers
+    #[serde(default)]
+    pub tier: Option<String>,
+    /// Order in which the badge was minted for the event, starting at 1. Kept on transfer
+    #[serde(default)]
+    pub serial: Option<u32>,
+    /// The cw721 contract the badge was exported to. Exported badges can't be transferred here
+    #[serde(default)]
+    pub exported_to: Option<Addr>,
+    /// The minter's reason for issuing the badge, for the organizers' audit trail
+    #[serde(default)]
+    pub memo: Option<String>,
+    /// A self-claimed badge is provisional until this timestamp: it may be challenged, and
+    /// can't be transferred or exported
+    #[serde(default)]
+    pub challenge_until: Option<u64>,
+    /// Small structured data given by the minter, like a ticket id or a workshop track
+    #[serde(default)]
+    pub attributes: Vec<BadgeAttribute>,
+    /// Off-chain key the holder bound to the badge. Cleared when the badge is transferred
+    #[serde(default)]
+    pub bound_key: Option<BoundKey>,
+    /// Artwork of this badge only (eg. with the attendee's number rendered in), shown instead
+    /// of the tier or event image
+    #[serde(default)]
+    pub image: Option<String>,
+    /// The holder erased the note, memo, attributes, image and bound key. The badge still
+    /// counts as an attendance
+    #[serde(default)]
+    pub erased: bool,
+    /// The note, attributes and image can no longer be changed, only erased by the holder
+    #[serde(default)]
+    pub metadata_frozen: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeKind {
+    Registration,
+    Mint,
+}
+
+impl FeeKind {
+    /// The price table of the fee, by denom
+    pub fn prices(self) -> Map<'static, &'static str, Uint128> {
+        match self {
+            FeeKind::Registration => REGISTRATION_FEES,
+            FeeKind::Mint => MINT_FEES,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeDiscount {
+    /// Completed events the organizer needs for the discount
+    pub completed_events: u32,
+    /// Share of the registration fee taken off, eg. 0.5 halves it
+    pub discount: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAlgorithm {
+    Ed25519,
+    /// Compressed or uncompressed public key
+    Secp256k1,
+}
+
+/// A public key off-chain systems (eg. a Discord bot or a door) may challenge instead of
+/// asking for a wallet connection
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BoundKey {
+    pub algorithm: KeyAlgorithm,
+    pub pubkey: Binary,
+    pub bound_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BadgeAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Provenance {
+    /// The address that authorized the mint: the owner or minter, the owner who reserved
+    /// the badge or the kiosk. For claim codes, the attendee themselves
+    pub minted_by: Addr,
+    pub height: u64,
+    pub time: u64,
+    pub flow: MintFlow,
+    /// The address credited by the attendee for bringing them to the event, on claims
+    #[serde(default)]
+    pub referrer: Option<Addr>,
+    /// Name of the registered kiosk that minted the badge
+    #[serde(default)]
+    pub kiosk: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AuditEntry {
+    /// Type of the event the action emitted
+    pub action: String,
+    pub sender: Addr,
+    pub height: u64,
+    pub time: u64,
+    /// The event's attributes after `action` and `sender`
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ChangeEntry {
+    pub height: u64,
+    pub change: Change,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Change {
+    EventRegistered { event: String },
+    BadgeMinted { event: String, attendee: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RecentMint {
+    pub event: String,
+    pub attendee: Addr,
+    pub time: u64,
+}
+
+/// What an address may do on an event it doesn't own. Each role includes the ones before it
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Checks attendees in
+    Viewer,
+    /// Mints badges, checks attendees out (which mints), issues tickets and runs kiosks
+    Minter,
+    /// Edits the event's metadata and settings, and grants the Minter and Viewer roles
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Minter => "minter",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoleGrant {
+    pub role: Role,
+    pub expires: Expiration,
+    /// Badges the grantee may still mint. Unlimited if unset
+    #[serde(default)]
+    pub quota: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MintFlow {
+    /// MintBadge by an owner or minter
+    Mint,
+    /// ClaimReserved
+    Reservation,
+    ClaimCode,
+    KioskNonce,
+    CheckOut,
+    /// ClaimFromWaitlist
+    Waitlist,
+    /// CheckInTicket
+    Ticket,
+    /// ReceiveNft, for a ticket of the event's cw721 collection
+    NftTicket,
+    /// Receive, for a deposit of the event's cw20 ticket token
+    Cw20Ticket,
+    /// OpenClaim, by the attendee themselves
+    OpenClaim,
+    /// ClaimWithSecret, by the attendee themselves
+    ClaimSecret,
+    /// Streak badge of a series, minted by the contract
+    Streak,
+    /// ClaimAchievement, by the holder of the required badges
+    Achievement,
+}
+
+/// The step a migration continues from. Every step is handled in batches
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStep {
+    /// Moving badges out of LEGACY_BADGES
+    MoveBadges,
+    /// Removing the badge counters before they are rebuilt
+    ClearCounts,
+    /// Recounting badges, after this (event, attendee) key
+    CountBadges { after: Option<(String, Addr)> },
+    /// Removing the badge listing entries and organizer counters before they are rebuilt
+    ClearListings,
+    /// Queueing, listing and counting the events after this one
+    IndexEvents { after: Option<String> },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyBadgeData {
     pub was_late: bool,
+    pub note: Option<String>,
+    pub valid_until: Option<u64>,
+}
+
+impl BadgeData {
+    pub fn is_expired(&self, now: u64) -> bool {
+        matches!(self.valid_until, Some(valid_until) if now > valid_until)
+    }
+
+    pub fn is_provisional(&self, now: u64) -> bool {
+        matches!(self.challenge_until, Some(until) if now < until)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct OrganizerCounts {
+    pub events: u32,
+    /// Badges ever minted into the organizer's events, burned ones included
+    pub badges: u64,
+    /// Events finalized with badges, cancelled ones excluded
+    #[serde(default)]
+    pub completed: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct BadgeCount {
+    pub total: u32,
+    /// Badges with was_late unset
+    pub on_time: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Rating {
+    pub score: u8,
+    pub comment: Option<String>,
+}
+
+/// What is kept of an event's minting state once it was archived. Badges and metadata stay
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ArchivedEvent {
+    pub archived_by: Addr,
+    pub archived_at: u64,
+    /// Badges held when the event was archived
+    pub badges: u64,
+    /// Badges ever minted, including burned ones
+    pub minted: u32,
+    pub occurrences: u32,
+    /// Hex merkle root over the holders' addresses, in address order. None without holders
+    pub attendees_root: Option<String>,
+    /// Hex sha256 of the event metadata, as JSON
+    pub metadata_hash: String,
+}
+
+/// A forced transfer of an event announced by the admin, subject to a delay
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EventRecovery {
+    pub new_owner: Addr,
+    pub announced_at: u64,
+    /// The transfer can't be completed before this timestamp
+    pub executable_at: u64,
+}
+
+/// Commitment to the holders of an event when it was frozen, so other chains can check
+/// attendance with a merkle proof
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AttendanceRoot {
+    /// Hex merkle root over the holders' addresses, in address order. None without holders
+    pub root: Option<String>,
+    pub attendees: u64,
+    pub height: u64,
+    pub time: u64,
+}
+
+/// The attendance snapshot of an event handed off to a merkle airdrop contract
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Airdrop {
+    /// Hex merkle root over the attendees and their amount, as cw20-merkle-airdrop expects
+    pub root: String,
+    /// Sent to every attendee of the snapshot
+    pub amount_per_attendee: Coin,
+    pub attendees: u64,
+    pub created_at: u64,
+}
+
+/// A badge held on a counterpart deployment, verified against its attendance root
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RemoteBadge {
+    /// The root the proof was checked against. The badge is no longer verified once the admin
+    /// replaces it
+    pub root: String,
+    pub verified_at: u64,
+}
+
+/// How the address of a foreign badge holder is encoded
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressFormat {
+    /// Bech32 address of a Cosmos chain using this prefix
+    Bech32 { prefix: String },
+    /// 0x-prefixed hex address of an EVM chain
+    Evm,
+}
+
+/// A badge minted to an address that isn't valid on this chain. It is kept apart from the
+/// local badges and can't be transferred
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ForeignBadge {
+    pub format: AddressFormat,
+    pub minter: Addr,
+    pub minted_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct RatingTotals {
+    pub count: u32,
+    pub sum: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct ClaimCode {
+    /// Set once the code was used. Used codes are kept so they can't be uploaded again
+    pub claimed_by: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct KioskNonce {
+    /// Hex encoded sha256 of the nonce
+    pub hash: String,
+    /// The nonce can be revealed until this timestamp
+    pub expires: u64,
+}
+
+/// A check-in station (eg. one entrance of a venue) that may only mint, optionally in one
+/// tier and during a window, without being granted a role
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Kiosk {
+    pub name: String,
+    /// Badges minted by the kiosk are in this tier
+    pub tier: Option<String>,
+    /// The kiosk may only mint during this window, if set
+    pub window: Option<ClaimWindow>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RewardPool {
+    /// Sent to every new badge holder while the balance covers it
+    pub payout: Coin,
+    pub balance: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20Reward {
+    pub token: Addr,
+    pub amount: Uint128,
+    /// The owner who configured the reward, whose allowance the tokens are taken from
+    pub funder: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NftTicketCollection {
+    pub collection: Addr,
+    /// Burn received tickets instead of keeping them
+    pub burn: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Raffle {
+    pub funder: Addr,
+    /// Split equally among the winners. What can't be split goes back to the funder
+    pub prize: Coin,
+    /// Paid to the Nois proxy when the draw is requested, in the prize denom
+    pub fee: Uint128,
+    pub winners: u32,
+    pub status: RaffleStatus,
+    /// The winners, once drawn
+    pub drawn: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RaffleStatus {
+    Funded,
+    /// Waiting for the randomness callback
+    Drawing,
+    Drawn,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Sponsorship {
+    pub name: String,
+    pub logo: Option<String>,
+    pub funds: Vec<Coin>,
+    /// Paid to the event owner. The sponsorship stays listed
+    pub released: bool,
+}
+
+/// Funds the organizers set aside to pay the fees of their allowlisted attendees' claims
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GasBudget {
+    /// What is left after the allowances granted so far
+    pub funds: Coin,
+    /// Spend limit of the fee allowance granted to each attendee
+    pub allowance: Uint128,
+    /// Attendees granted an allowance
+    pub granted: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Cw20TicketToken {
+    pub token: Addr,
+    /// Amount deposited for one badge
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Deposit {
+    pub depositor: Addr,
+    pub amount: Coin,
+}
+
+/// A partial MintBadges batch, continued with ContinueBatch
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintBatch {
+    pub attendees: Vec<String>,
+    /// Index of the next attendee to mint
+    pub next: u32,
+    /// Mint fee paid per attendee, refunded for the attendees that fail
+    pub fee: Option<Coin>,
+}
+
+/// The last key Cleanup looked at in each map, None to start over from the first one
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct CleanupCursor {
+    pub reservation: Option<(Addr, String)>,
+    pub grant: Option<(String, Addr)>,
+    pub claim_code: Option<(String, String)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Reservation {
+    pub reserved_by: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Ticket {
+    pub issued_by: Addr,
+    /// Holder the ticket was issued to, before any transfer
+    pub issued_to: Addr,
+}
+
+/// Timestamps in seconds, both inclusive
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimWindow {
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct OccurrenceData {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub attendees: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Translation {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeriesData {
+    pub owner: Addr,
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub streak: Option<SeriesStreak>,
+}
+
+/// Holders of badges of all the required events may claim a badge of `event`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Achievement {
+    pub owner: Addr,
+    pub event: String,
+    pub required: Vec<String>,
+}
+
+/// A badge of `event` is minted to attendees of `length` consecutive events of the series,
+/// in start time order
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SeriesStreak {
+    pub length: u32,
+    pub event: String,
 }